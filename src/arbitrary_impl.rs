@@ -0,0 +1,51 @@
+use core::ops::RangeInclusive;
+
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::Vec2D;
+
+/// The dimensions used by the `Arbitrary` implementation.
+const DEFAULT_DIMENSIONS: RangeInclusive<usize> = 1..=16;
+
+/// Generates grids with a width and height between 1 and 16. Use
+/// [`Vec2D::arbitrary_with_dimensions`] for other sizes.
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Vec2D<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::arbitrary_with_dimensions(u, DEFAULT_DIMENSIONS, DEFAULT_DIMENSIONS)
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Generates an arbitrary grid from `u`, whose width and height lie in
+    /// the given ranges.
+    ///
+    /// # Errors
+    /// Returns `arbitrary::Error::IncorrectFormat` if either range only
+    /// allows zero, or if the generated dimensions are too large to allocate,
+    /// and forwards any error from generating the cells.
+    pub fn arbitrary_with_dimensions<'a>(
+        u: &mut Unstructured<'a>,
+        widths: RangeInclusive<usize>,
+        heights: RangeInclusive<usize>,
+    ) -> Result<Self>
+    where
+        T: Arbitrary<'a>,
+    {
+        let clamp = |range: RangeInclusive<usize>| (*range.start()).max(1)..=*range.end();
+        let (widths, heights) = (clamp(widths), clamp(heights));
+        if widths.is_empty() || heights.is_empty() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+
+        let width = u.int_in_range(widths)?;
+        let height = u.int_in_range(heights)?;
+        let len =
+            Self::checked_len(width, height).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        let cells = (0..len)
+            .map(|_| T::arbitrary(u))
+            .collect::<Result<Vec<T>>>()?;
+
+        Ok(Vec2D { cells, width })
+    }
+}
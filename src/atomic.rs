@@ -0,0 +1,93 @@
+use core::sync::atomic::Ordering;
+
+use crate::Vec2D;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An atomic type that can be stored in a [`Vec2D`], to be shared between
+/// threads without locks.
+///
+/// This is implemented for the atomic booleans and unsigned integers
+/// supported by the target, and can't be implemented outside of this crate.
+pub trait AtomicCell: sealed::Sealed {
+    /// The plain type of the atomic's value.
+    type Value;
+
+    /// Creates a new atomic holding `value`.
+    fn new(value: Self::Value) -> Self;
+
+    /// Consumes the atomic and returns its value.
+    fn into_inner(self) -> Self::Value;
+
+    /// Loads the value with `Ordering::Relaxed`.
+    fn load_relaxed(&self) -> Self::Value;
+}
+
+macro_rules! atomic_cell {
+    ($($size:literal => $atomic:ident($value:ty)),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $size)]
+            impl sealed::Sealed for core::sync::atomic::$atomic {}
+
+            #[cfg(target_has_atomic = $size)]
+            impl AtomicCell for core::sync::atomic::$atomic {
+                type Value = $value;
+
+                fn new(value: $value) -> Self {
+                    Self::new(value)
+                }
+
+                fn into_inner(self) -> $value {
+                    self.into_inner()
+                }
+
+                fn load_relaxed(&self) -> $value {
+                    self.load(Ordering::Relaxed)
+                }
+            }
+        )*
+    };
+}
+
+atomic_cell! {
+    "8" => AtomicBool(bool),
+    "8" => AtomicU8(u8),
+    "16" => AtomicU16(u16),
+    "32" => AtomicU32(u32),
+    "64" => AtomicU64(u64),
+    "ptr" => AtomicUsize(usize),
+}
+
+impl<T> Vec2D<T> {
+    /// Converts the grid into a grid of atomics, which can be updated from
+    /// multiple threads without locks.
+    pub fn into_atomic<A: AtomicCell<Value = T>>(self) -> Vec2D<A> {
+        Vec2D {
+            cells: self.cells.into_iter().map(A::new).collect(),
+            width: self.width,
+        }
+    }
+
+    /// Converts a grid of atomics back into a grid of plain values.
+    pub fn from_atomic<A: AtomicCell<Value = T>>(grid: Vec2D<A>) -> Self {
+        Vec2D {
+            cells: grid.cells.into_iter().map(A::into_inner).collect(),
+            width: grid.width,
+        }
+    }
+}
+
+impl<A: AtomicCell> Vec2D<A> {
+    /// Returns a snapshot of all cells, loaded with `Ordering::Relaxed`.
+    ///
+    /// Cells are loaded one by one, so writes from other threads may be
+    /// visible in some cells of the snapshot, but not in others.
+    pub fn load_grid(&self) -> Vec2D<A::Value> {
+        Vec2D {
+            cells: self.cells.iter().map(A::load_relaxed).collect(),
+            width: self.width,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A boolean grid storing one bit per cell, in row-major order.
+///
+/// This takes an eighth of the memory of a `Vec2D<bool>`, which matters for
+/// large visibility masks and collision maps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    // Bits past `width * height` are always unset.
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+/// Indexes into the grid using `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl core::ops::Index<(usize, usize)> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < self.width && y < self.height,
+            // Panic message
+            "BitGrid index out of bounds: (x: {}, y: {}) in a {}x{} grid.",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+
+        if self.bit(y * self.width + x) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+/// Packs a boolean grid into bits.
+impl From<&Vec2D<bool>> for BitGrid {
+    fn from(value: &Vec2D<bool>) -> Self {
+        let mut grid = Self {
+            words: vec![0; value.cells.len().div_ceil(BITS)],
+            width: value.width,
+            height: value.height(),
+        };
+        for (idx, _) in value.cells.iter().enumerate().filter(|(_, cell)| **cell) {
+            grid.words[idx / BITS] |= 1 << (idx % BITS);
+        }
+        grid
+    }
+}
+
+/// Unpacks the bits into a boolean grid.
+impl From<&BitGrid> for Vec2D<bool> {
+    fn from(value: &BitGrid) -> Self {
+        Vec2D {
+            cells: (0..value.width * value.height)
+                .map(|idx| value.bit(idx))
+                .collect(),
+            width: value.width,
+        }
+    }
+}
+
+impl BitGrid {
+    /// Constructs a new grid with all cells set to `value`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
+    /// Returns `Vec2DErr::ZeroHeight` if `height == 0`.
+    /// Returns `Vec2DErr::TooLarge` if `width * height` overflows.
+    pub fn new(width: usize, height: usize, value: bool) -> Result<Self, Vec2DErr> {
+        if width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        } else if height == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        }
+
+        let bits = width.checked_mul(height).ok_or(Vec2DErr::TooLarge)?;
+        let mut grid = Self {
+            words: vec![0; bits.div_ceil(BITS)],
+            width,
+            height,
+        };
+        grid.fill(value);
+        Ok(grid)
+    }
+
+    #[inline]
+    fn bit(&self, idx: usize) -> bool {
+        self.words[idx / BITS] & (1 << (idx % BITS)) != 0
+    }
+
+    /// Returns the width of the grid.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the value of the cell at `(x, y)`, if it exists.
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        if x < self.width && y < self.height {
+            Some(self.bit(y * self.width + x))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the cell at `(x, y)` to `value`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the coordinates are out of bounds.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) -> Result<(), Vec2DErr> {
+        if x >= self.width || y >= self.height {
+            return Err(Vec2DErr::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        let idx = y * self.width + x;
+        if value {
+            self.words[idx / BITS] |= 1 << (idx % BITS);
+        } else {
+            self.words[idx / BITS] &= !(1 << (idx % BITS));
+        }
+        Ok(())
+    }
+
+    /// Sets all cells to `value`.
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { u64::MAX } else { 0 });
+
+        // Keep the bits past the last cell unset.
+        let used = (self.width * self.height) % BITS;
+        if value && used != 0 {
+            let last = self.words.len() - 1;
+            self.words[last] = (1 << used) - 1;
+        }
+    }
+
+    /// Returns the number of cells set to `true`.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterates over all cells, yielding their `(x, y)` coordinates and values.
+    pub fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), bool)> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |idx| ((idx % width, idx / width), self.bit(idx)))
+    }
+}
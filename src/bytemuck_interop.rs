@@ -0,0 +1,37 @@
+use bytemuck::Pod;
+
+use crate::{Vec2D, Vec2DErr};
+
+impl<T: Pod> Vec2D<T> {
+    /// Returns the raw bytes of all cells in row-major order.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.cells)
+    }
+
+    /// Returns the raw bytes of all cells in row-major order, mutably.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(&mut self.cells)
+    }
+
+    /// Constructs a grid from the raw bytes of its cells in row-major order.
+    ///
+    /// The bytes don't have to be aligned for `T`, they are copied into a
+    /// new, properly aligned buffer.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ByteLengthMismatch` if the number of bytes is not a
+    /// multiple of the cell size.
+    ///
+    /// Otherwise, returns the same errors as [`Vec2D::from_vec`].
+    pub fn from_bytes(width: usize, bytes: &[u8]) -> Result<Self, Vec2DErr> {
+        let cell_size = size_of::<T>();
+        if !bytes.len().is_multiple_of(cell_size) {
+            return Err(Vec2DErr::ByteLengthMismatch {
+                len: bytes.len(),
+                cell_size,
+            });
+        }
+
+        Self::from_vec(bytemuck::pod_collect_to_vec(bytes), width)
+    }
+}
@@ -0,0 +1,120 @@
+use core::cell::Cell;
+
+use crate::{Grid2D, Vec2D};
+
+/// A view of a [`Vec2D`] whose cells can be written through shared
+/// references.
+///
+/// Created by [`Vec2D::as_cell_grid`]. This allows reading a cell's
+/// neighbors while writing to the cell itself, without index juggling.
+pub struct CellGrid<'a, T> {
+    cells: &'a [Cell<T>],
+    width: usize,
+}
+
+impl<T> Clone for CellGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CellGrid<'_, T> {}
+
+// `Cell<T>` is only `Debug` if its value can be copied out.
+impl<T: Copy + core::fmt::Debug> core::fmt::Debug for CellGrid<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CellGrid")
+            .field("cells", &self.cells)
+            .field("width", &self.width)
+            .finish()
+    }
+}
+
+/// Indexes into the grid using `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::Index<(usize, usize)> for CellGrid<'_, T> {
+    type Output = Cell<T>;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < self.width && y < self.height(),
+            // Panic message
+            "CellGrid index out of bounds: (x: {}, y: {}) in a {}x{} grid.",
+            x,
+            y,
+            self.width,
+            self.height()
+        );
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Returns a view of the grid whose cells are [`Cell`]s, so they can be
+    /// written to while other cells are borrowed.
+    pub fn as_cell_grid(&mut self) -> CellGrid<'_, T> {
+        let width = self.width;
+
+        CellGrid {
+            cells: Cell::from_mut(&mut self.cells[..]).as_slice_of_cells(),
+            width,
+        }
+    }
+}
+
+impl<'a, T> CellGrid<'a, T> {
+    /// Returns the width of the grid.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        if self.width == 0 {
+            return 0;
+        }
+
+        self.cells.len() / self.width
+    }
+
+    /// Returns the cell at `(x, y)`, if it exists.
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a Cell<T>> {
+        if x < self.width && y < self.height() {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a shared slice of all cells in row-major order.
+    #[inline]
+    pub const fn cells(&self) -> &'a [Cell<T>] {
+        self.cells
+    }
+
+    /// Iterates over the grid's rows as slices of cells.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [Cell<T>]> + use<'a, T> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+}
+
+impl<T> Grid2D for CellGrid<'_, T> {
+    type Cell = Cell<T>;
+
+    fn width(&self) -> usize {
+        CellGrid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        CellGrid::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Cell<T>> {
+        CellGrid::get(self, x, y)
+    }
+}
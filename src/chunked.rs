@@ -0,0 +1,136 @@
+use alloc::collections::BTreeMap;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// An unbounded grid made of fixed-size [`Vec2D`] chunks, which are only
+/// allocated once a cell inside them is written to.
+///
+/// Cells are addressed with signed, global `(x, y)` coordinates, and chunks
+/// with `(chunk_x, chunk_y)` coordinates, where chunk `(0, 0)` covers the
+/// cells from `(0, 0)` up to `(chunk_width - 1, chunk_height - 1)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedGrid<T> {
+    chunks: BTreeMap<(i64, i64), Vec2D<T>>,
+    chunk_width: usize,
+    chunk_height: usize,
+    default: T,
+}
+
+impl<T: Clone> ChunkedGrid<T> {
+    /// Constructs a new grid with no allocated chunks, in which every cell
+    /// reads as `default` until it is written to.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `chunk_width == 0`.
+    /// Returns `Vec2DErr::ZeroHeight` if `chunk_height == 0`.
+    /// Returns `Vec2DErr::TooLarge` if a chunk's cells wouldn't fit into
+    /// memory.
+    pub fn new(chunk_width: usize, chunk_height: usize, default: T) -> Result<Self, Vec2DErr> {
+        if chunk_width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        } else if chunk_height == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        }
+        Vec2D::<T>::checked_len(chunk_width, chunk_height)?;
+
+        Ok(Self {
+            chunks: BTreeMap::new(),
+            chunk_width,
+            chunk_height,
+            default,
+        })
+    }
+
+    /// Returns the `(width, height)` of every chunk.
+    #[inline]
+    pub const fn chunk_size(&self) -> (usize, usize) {
+        (self.chunk_width, self.chunk_height)
+    }
+
+    /// Returns the number of allocated chunks.
+    #[inline]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Splits global coordinates into the coordinates of the chunk containing
+    /// them, and the local coordinates within that chunk.
+    pub fn split_coords(&self, x: i64, y: i64) -> ((i64, i64), (usize, usize)) {
+        let (w, h) = (self.chunk_width as i64, self.chunk_height as i64);
+
+        (
+            (x.div_euclid(w), y.div_euclid(h)),
+            (x.rem_euclid(w) as usize, y.rem_euclid(h) as usize),
+        )
+    }
+
+    /// Returns a shared reference to the cell at `(x, y)`.
+    ///
+    /// Cells in chunks that weren't allocated yet read as the default value.
+    pub fn get(&self, x: i64, y: i64) -> &T {
+        let (chunk, local) = self.split_coords(x, y);
+
+        match self.chunks.get(&chunk) {
+            Some(chunk) => &chunk[local],
+            None => &self.default,
+        }
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`, allocating its
+    /// chunk if needed.
+    pub fn get_mut(&mut self, x: i64, y: i64) -> &mut T {
+        let (chunk, (local_x, local_y)) = self.split_coords(x, y);
+        let idx = local_y * self.chunk_width + local_x;
+
+        &mut self.chunk_mut(chunk.0, chunk.1)[idx]
+    }
+
+    /// Sets the cell at `(x, y)` to `value`, allocating its chunk if needed.
+    pub fn set(&mut self, x: i64, y: i64, value: T) {
+        *self.get_mut(x, y) = value;
+    }
+
+    /// Returns the chunk at `(chunk_x, chunk_y)`, if it was allocated.
+    pub fn chunk(&self, chunk_x: i64, chunk_y: i64) -> Option<&Vec2D<T>> {
+        self.chunks.get(&(chunk_x, chunk_y))
+    }
+
+    /// Returns the cells of the chunk at `(chunk_x, chunk_y)` mutably in
+    /// row-major order, allocating the chunk if needed.
+    ///
+    /// Only the cells are exposed, so the chunk's dimensions can't change.
+    pub fn chunk_mut(&mut self, chunk_x: i64, chunk_y: i64) -> &mut [T] {
+        let (width, height) = (self.chunk_width, self.chunk_height);
+        let default = &self.default;
+
+        self.chunks
+            .entry((chunk_x, chunk_y))
+            .or_insert_with(|| Vec2D {
+                cells: alloc::vec![default.clone(); width * height],
+                width,
+            })
+            .cells
+            .as_mut_slice()
+    }
+
+    /// Deallocates the chunk at `(chunk_x, chunk_y)`, returning it, if it
+    /// was allocated.
+    pub fn remove_chunk(&mut self, chunk_x: i64, chunk_y: i64) -> Option<Vec2D<T>> {
+        self.chunks.remove(&(chunk_x, chunk_y))
+    }
+
+    /// Iterates over all allocated chunks, ordered by their coordinates,
+    /// yielding their `(chunk_x, chunk_y)` coordinates and contents.
+    pub fn iter_chunks(&self) -> impl Iterator<Item = ((i64, i64), &Vec2D<T>)> {
+        self.chunks.iter().map(|(&pos, chunk)| (pos, chunk))
+    }
+
+    /// Iterates mutably over all allocated chunks, ordered by their
+    /// coordinates, yielding their `(chunk_x, chunk_y)` coordinates and
+    /// cells in row-major order.
+    pub fn iter_chunks_mut(&mut self) -> impl Iterator<Item = ((i64, i64), &mut [T])> {
+        self.chunks
+            .iter_mut()
+            .map(|(&pos, chunk)| (pos, chunk.cells.as_mut_slice()))
+    }
+}
@@ -0,0 +1,122 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Vec2D;
+
+/// An edge between two horizontally (`false`) or vertically (`true`)
+/// adjacent cells, identified by the first cell's coordinates.
+type EdgeKey = (bool, usize, usize);
+
+// The edges of a square, relative to its top-left cell.
+const TOP: EdgeKey = (false, 0, 0);
+const RIGHT: EdgeKey = (true, 1, 0);
+const BOTTOM: EdgeKey = (false, 0, 1);
+const LEFT: EdgeKey = (true, 0, 0);
+
+impl<T: Copy + Into<f32>> Vec2D<T> {
+    /// Extracts the contour lines at `threshold` using marching squares,
+    /// treating cells as sample points at their integer coordinates.
+    ///
+    /// Every contour is a polyline of interpolated `(x, y)` points separating
+    /// the cells below `threshold` from the rest. Closed contours end with
+    /// their first point; contours reaching the edges of the grid stay open.
+    /// Ambiguous saddle squares are resolved using the average of their four
+    /// corners.
+    pub fn contours(&self, threshold: f32) -> Vec<Vec<(f32, f32)>> {
+        let value = |x: usize, y: usize| -> f32 { self.cells[y * self.width + x].into() };
+
+        let mut segments: Vec<(EdgeKey, EdgeKey)> = Vec::new();
+        for y in 0..self.height().saturating_sub(1) {
+            for x in 0..self.width - 1 {
+                let corners = [
+                    value(x, y),
+                    value(x + 1, y),
+                    value(x + 1, y + 1),
+                    value(x, y + 1),
+                ];
+                let case = corners
+                    .iter()
+                    .fold(0, |case, &v| (case << 1) | usize::from(v >= threshold));
+                let center = corners.iter().sum::<f32>() / 4.0 >= threshold;
+
+                // Bits: top-left = 8, top-right = 4, bottom-right = 2,
+                // bottom-left = 1.
+                let edges: &[(EdgeKey, EdgeKey)] = match case {
+                    1 | 14 => &[(LEFT, BOTTOM)],
+                    2 | 13 => &[(BOTTOM, RIGHT)],
+                    3 | 12 => &[(LEFT, RIGHT)],
+                    4 | 11 => &[(TOP, RIGHT)],
+                    6 | 9 => &[(TOP, BOTTOM)],
+                    7 | 8 => &[(LEFT, TOP)],
+                    5 if center => &[(LEFT, TOP), (BOTTOM, RIGHT)],
+                    5 => &[(LEFT, BOTTOM), (TOP, RIGHT)],
+                    10 if center => &[(TOP, RIGHT), (LEFT, BOTTOM)],
+                    10 => &[(LEFT, TOP), (BOTTOM, RIGHT)],
+                    _ => &[],
+                };
+
+                let at = |(vertical, dx, dy): EdgeKey| (vertical, x + dx, y + dy);
+                segments.extend(edges.iter().map(|&(a, b)| (at(a), at(b))));
+            }
+        }
+
+        let point = |(vertical, x, y): EdgeKey| {
+            let (a, b) = if vertical {
+                (value(x, y), value(x, y + 1))
+            } else {
+                (value(x, y), value(x + 1, y))
+            };
+            let t = (threshold - a) / (b - a);
+
+            if vertical {
+                (x as f32, y as f32 + t)
+            } else {
+                (x as f32 + t, y as f32)
+            }
+        };
+
+        chain_segments(&segments)
+            .into_iter()
+            .map(|line| line.into_iter().map(point).collect())
+            .collect()
+    }
+}
+
+/// Joins segments sharing an edge into polylines.
+fn chain_segments(segments: &[(EdgeKey, EdgeKey)]) -> Vec<Vec<EdgeKey>> {
+    let mut by_edge: BTreeMap<EdgeKey, Vec<usize>> = BTreeMap::new();
+    for (i, &(a, b)) in segments.iter().enumerate() {
+        by_edge.entry(a).or_default().push(i);
+        by_edge.entry(b).or_default().push(i);
+    }
+
+    let mut used = vec![false; segments.len()];
+    // Follows unused segments from `edge`, returning the edges visited.
+    let follow = |mut edge: EdgeKey, used: &mut Vec<bool>| {
+        let mut edges = Vec::new();
+        while let Some(&i) = by_edge[&edge].iter().find(|&&i| !used[i]) {
+            used[i] = true;
+            let (a, b) = segments[i];
+            edge = if a == edge { b } else { a };
+            edges.push(edge);
+        }
+        edges
+    };
+
+    let mut lines = Vec::new();
+    for (i, &(a, b)) in segments.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+
+        let mut line = follow(a, &mut used);
+        line.reverse();
+        line.push(a);
+        line.push(b);
+        line.extend(follow(b, &mut used));
+        lines.push(line);
+    }
+    lines
+}
@@ -0,0 +1,131 @@
+use core::ops::{Add, Index, IndexMut, Sub};
+
+use crate::{Direction, Vec2D};
+
+/// The `(x, y)` coordinates of a cell, where `(0, 0)` is the top-left
+/// corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coord {
+    /// Constructs the coordinates `(x, y)`.
+    pub const fn new(x: usize, y: usize) -> Self {
+        Coord { x, y }
+    }
+
+    /// Returns the coordinates moved by `(dx, dy)`, or `None` if they would
+    /// underflow or overflow.
+    pub const fn offset(self, dx: isize, dy: isize) -> Option<Coord> {
+        match (self.x.checked_add_signed(dx), self.y.checked_add_signed(dy)) {
+            (Some(x), Some(y)) => Some(Coord { x, y }),
+            _ => None,
+        }
+    }
+
+    /// Returns the coordinates one step in `direction`, or `None` if they
+    /// would underflow or overflow.
+    pub const fn step(self, direction: Direction) -> Option<Coord> {
+        let (dx, dy) = direction.offset();
+        self.offset(dx, dy)
+    }
+
+    /// Returns the Manhattan (4-connected) distance between both coordinates.
+    pub const fn manhattan(self, other: Coord) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Returns the Chebyshev (8-connected) distance between both coordinates.
+    pub const fn chebyshev(self, other: Coord) -> usize {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        if dx > dy { dx } else { dy }
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((x, y): (usize, usize)) -> Self {
+        Coord { x, y }
+    }
+}
+
+impl From<Coord> for (usize, usize) {
+    fn from(value: Coord) -> Self {
+        (value.x, value.y)
+    }
+}
+
+/// Adds the coordinates component-wise.
+///
+/// # Panics
+/// Panics in debug builds if either component would overflow.
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Coord) -> Self::Output {
+        Coord::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+/// Subtracts the coordinates component-wise.
+///
+/// # Panics
+/// Panics in debug builds if either component would underflow.
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Coord) -> Self::Output {
+        Coord::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// Moves the coordinates by a `(dx, dy)` offset, like [`Coord::offset`].
+impl Add<(isize, isize)> for Coord {
+    type Output = Option<Coord>;
+
+    fn add(self, (dx, dy): (isize, isize)) -> Self::Output {
+        self.offset(dx, dy)
+    }
+}
+
+/// Moves the coordinates one step in a direction, like [`Coord::step`].
+impl Add<Direction> for Coord {
+    type Output = Option<Coord>;
+
+    fn add(self, direction: Direction) -> Self::Output {
+        self.step(direction)
+    }
+}
+
+/// Moves the coordinates one step against a direction, returning `None` if
+/// they would underflow or overflow.
+impl Sub<Direction> for Coord {
+    type Output = Option<Coord>;
+
+    fn sub(self, direction: Direction) -> Self::Output {
+        self.step(direction.opposite())
+    }
+}
+
+/// Indexes into the grid using a [`Coord`].
+///
+/// # Panics
+/// Panics if the coordinates are out of bounds.
+impl<T> Index<Coord> for Vec2D<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coord) -> &Self::Output {
+        &self[(pos.x, pos.y)]
+    }
+}
+
+/// Mutably indexes into the grid using a [`Coord`].
+///
+/// # Panics
+/// Panics if the coordinates are out of bounds.
+impl<T> IndexMut<Coord> for Vec2D<T> {
+    fn index_mut(&mut self, pos: Coord) -> &mut Self::Output {
+        &mut self[(pos.x, pos.y)]
+    }
+}
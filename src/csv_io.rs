@@ -0,0 +1,158 @@
+use core::fmt;
+use core::str::FromStr;
+use std::io;
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::Vec2D;
+
+/// An error produced while reading a grid from CSV.
+///
+/// Row and column numbers are 1-based, as they refer to positions in the
+/// source records rather than grid coordinates.
+#[derive(Debug)]
+pub enum CsvError<E> {
+    Csv(csv::Error),
+    EmptySource,
+    InvalidCell {
+        row: usize,
+        column: usize,
+        error: E,
+    },
+    WidthMismatch {
+        row: usize,
+        length: usize,
+        expected: usize,
+    },
+}
+impl<E: fmt::Debug + fmt::Display> std::error::Error for CsvError<E> {}
+
+impl<E> From<csv::Error> for CsvError<E> {
+    fn from(value: csv::Error) -> Self {
+        CsvError::Csv(value)
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Writes the grid as comma-separated values, one record per row.
+    pub fn to_csv<W: io::Write>(&self, writer: W) -> Result<(), csv::Error>
+    where
+        T: fmt::Display,
+    {
+        self.to_csv_with_delimiter(writer, b',')
+    }
+
+    /// Writes the grid as delimiter-separated values, one record per row.
+    pub fn to_csv_with_delimiter<W: io::Write>(
+        &self,
+        writer: W,
+        delimiter: u8,
+    ) -> Result<(), csv::Error>
+    where
+        T: fmt::Display,
+    {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter)
+            .has_headers(false)
+            .from_writer(writer);
+
+        for row in self.iter_rows() {
+            writer.write_record(row.iter().map(ToString::to_string))?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads a grid from comma-separated values, one record per row.
+    /// All records must have the same length, which becomes the grid's width.
+    ///
+    /// # Errors
+    /// Returns `CsvError::Csv` if the input could not be read.
+    ///
+    /// Returns `CsvError::EmptySource` if there are no records.
+    ///
+    /// Returns `CsvError::WidthMismatch` if any record's length differs from
+    /// the length of the first record.
+    ///
+    /// Returns `CsvError::InvalidCell` with the position of the first field
+    /// that fails to parse.
+    pub fn from_csv<R: io::Read>(reader: R) -> Result<Self, CsvError<T::Err>>
+    where
+        T: FromStr,
+    {
+        Self::from_csv_with_delimiter(reader, b',')
+    }
+
+    /// Reads a grid from delimiter-separated values, one record per row.
+    /// All records must have the same length, which becomes the grid's width.
+    ///
+    /// # Errors
+    /// See [`Vec2D::from_csv`].
+    pub fn from_csv_with_delimiter<R: io::Read>(
+        reader: R,
+        delimiter: u8,
+    ) -> Result<Self, CsvError<T::Err>>
+    where
+        T: FromStr,
+    {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(reader);
+
+        let mut cells = Vec::new();
+        let mut width = 0;
+        for (row_idx, record) in reader.records().enumerate() {
+            let record = record?;
+
+            if row_idx == 0 {
+                width = record.len();
+            } else if record.len() != width {
+                return Err(CsvError::WidthMismatch {
+                    row: row_idx + 1,
+                    length: record.len(),
+                    expected: width,
+                });
+            }
+
+            for (column_idx, field) in record.iter().enumerate() {
+                let value = field.parse().map_err(|error| CsvError::InvalidCell {
+                    row: row_idx + 1,
+                    column: column_idx + 1,
+                    error,
+                })?;
+                cells.push(value);
+            }
+        }
+
+        if cells.is_empty() {
+            return Err(CsvError::EmptySource);
+        }
+        Ok(Self { cells, width })
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for CsvError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(err) => write!(f, "Failed to read CSV: {}", err),
+            CsvError::EmptySource => write!(f, "Source CSV is empty."),
+            CsvError::InvalidCell { row, column, error } => write!(
+                f,
+                "Invalid cell at row {}, column {}: {}",
+                row, column, error
+            ),
+            CsvError::WidthMismatch {
+                row,
+                length,
+                expected,
+            } => write!(
+                f,
+                "Row {} has length {}, but the grid's width is {}.",
+                row, length, expected
+            ),
+        }
+    }
+}
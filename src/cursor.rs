@@ -0,0 +1,110 @@
+use crate::{Direction, Vec2D, Vec2DErr};
+
+/// A movable position on a [`Vec2D`], for walking over the grid one step at
+/// a time.
+///
+/// Created by [`Vec2D::cursor`]. The cursor never leaves the grid: moves
+/// that would cross an edge fail and leave it in place.
+#[derive(Debug)]
+pub struct GridCursor<'a, T> {
+    grid: &'a Vec2D<T>,
+    x: usize,
+    y: usize,
+}
+
+impl<T> Clone for GridCursor<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridCursor<'_, T> {}
+
+impl<'a, T> GridCursor<'a, T> {
+    /// Returns the `(x, y)` coordinates of the cursor.
+    pub fn pos(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Returns the cell under the cursor.
+    pub fn get(&self) -> &'a T {
+        &self.grid[(self.x, self.y)]
+    }
+
+    /// Returns the coordinates one step in `direction`, if they are in
+    /// bounds.
+    fn target(&self, direction: Direction) -> Option<(usize, usize)> {
+        self.grid.step((self.x, self.y), direction)
+    }
+
+    /// Returns the cell one step in `direction`, without moving the cursor.
+    ///
+    /// Returns `None` if that cell is out of bounds.
+    pub fn peek(&self, direction: Direction) -> Option<&'a T> {
+        let (x, y) = self.target(direction)?;
+        Some(&self.grid[(x, y)])
+    }
+
+    /// Moves the cursor one step in `direction`, returning the new cell.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the step would leave the grid, in
+    /// which case the cursor doesn't move.
+    pub fn step(&mut self, direction: Direction) -> Result<&'a T, Vec2DErr> {
+        let Some(target) = self.target(direction) else {
+            // Negative coordinates wrap around, so they are reported as huge.
+            let (dx, dy) = direction.offset();
+            return Err(self.grid.out_of_bounds(
+                self.x.wrapping_add_signed(dx),
+                self.y.wrapping_add_signed(dy),
+            ));
+        };
+
+        (self.x, self.y) = target;
+        Ok(self.get())
+    }
+
+    /// Moves the cursor one step north (up).
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the cursor is on the top edge.
+    pub fn move_north(&mut self) -> Result<&'a T, Vec2DErr> {
+        self.step(Direction::North)
+    }
+
+    /// Moves the cursor one step east (right).
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the cursor is on the right edge.
+    pub fn move_east(&mut self) -> Result<&'a T, Vec2DErr> {
+        self.step(Direction::East)
+    }
+
+    /// Moves the cursor one step south (down).
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the cursor is on the bottom edge.
+    pub fn move_south(&mut self) -> Result<&'a T, Vec2DErr> {
+        self.step(Direction::South)
+    }
+
+    /// Moves the cursor one step west (left).
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the cursor is on the left edge.
+    pub fn move_west(&mut self) -> Result<&'a T, Vec2DErr> {
+        self.step(Direction::West)
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Returns a [`GridCursor`] positioned at `(x, y)`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if `(x, y)` is out of bounds.
+    pub fn cursor(&self, x: usize, y: usize) -> Result<GridCursor<'_, T>, Vec2DErr> {
+        self.try_index_of(x, y)?;
+
+        Ok(GridCursor { grid: self, x, y })
+    }
+}
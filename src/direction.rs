@@ -0,0 +1,126 @@
+use crate::Vec2D;
+
+/// A direction on the grid, where north points towards `y == 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four cardinal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the `(dx, dy)` offset of a single step in this direction.
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    pub const fn opposite(self) -> Direction {
+        self.rotate_cw().rotate_cw()
+    }
+
+    /// Returns the direction rotated 90 degrees clockwise.
+    pub const fn rotate_cw(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::NorthEast => Direction::SouthEast,
+            Direction::East => Direction::South,
+            Direction::SouthEast => Direction::SouthWest,
+            Direction::South => Direction::West,
+            Direction::SouthWest => Direction::NorthWest,
+            Direction::West => Direction::North,
+            Direction::NorthWest => Direction::NorthEast,
+        }
+    }
+
+    /// Returns the direction rotated 90 degrees counterclockwise.
+    pub const fn rotate_ccw(self) -> Direction {
+        self.opposite().rotate_cw()
+    }
+
+    /// Returns `true` for the four diagonal directions.
+    pub const fn is_diagonal(self) -> bool {
+        let (dx, dy) = self.offset();
+        dx != 0 && dy != 0
+    }
+
+    /// Applies a single step in this direction to `(x, y)`, returning `None`
+    /// if it would underflow.
+    pub(crate) fn apply(self, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+        let (dx, dy) = self.offset();
+
+        Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Returns the coordinates one step from `pos` in `direction`.
+    ///
+    /// Returns `None` if they are out of bounds.
+    pub fn step(&self, pos: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let (x, y) = direction.apply(pos)?;
+        self.index_of(x, y).map(|_| (x, y))
+    }
+
+    /// Iterates over the cells from `start` in `direction` until the edge of
+    /// the grid, yielding their `(x, y)` coordinates and values.
+    ///
+    /// The cell at `start` itself isn't yielded.
+    pub fn walk(
+        &self,
+        start: (usize, usize),
+        direction: Direction,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        core::iter::successors(self.step(start, direction), move |&pos| {
+            self.step(pos, direction)
+        })
+        .map(|(x, y)| ((x, y), &self[(x, y)]))
+    }
+
+    /// Iterates over the cells from `start` in `direction`, like
+    /// [`Vec2D::walk`], but stops before the first cell that doesn't satisfy
+    /// `pred`.
+    pub fn walk_while(
+        &self,
+        start: (usize, usize),
+        direction: Direction,
+        mut pred: impl FnMut(&T) -> bool,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.walk(start, direction)
+            .take_while(move |(_, cell)| pred(cell))
+    }
+}
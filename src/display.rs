@@ -0,0 +1,54 @@
+use core::fmt;
+
+use crate::Vec2D;
+
+/// A helper for printing a grid with custom separators.
+///
+/// Created by [`Vec2D::display_with`].
+pub struct DisplayWith<'a, T> {
+    grid: &'a Vec2D<T>,
+    separator: &'a str,
+    row_separator: &'a str,
+}
+
+impl<'a, T> DisplayWith<'a, T> {
+    pub(crate) fn new(grid: &'a Vec2D<T>, separator: &'a str, row_separator: &'a str) -> Self {
+        Self {
+            grid,
+            separator,
+            row_separator,
+        }
+    }
+}
+
+/// Prints the grid row by row, using the configured separators.
+///
+/// Formatting options (width, precision, ...) are applied to each cell.
+impl<T: fmt::Display> fmt::Display for DisplayWith<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.grid.iter_rows().enumerate() {
+            if y > 0 {
+                f.write_str(self.row_separator)?;
+            }
+
+            for (x, cell) in row.iter().enumerate() {
+                if x > 0 {
+                    f.write_str(self.separator)?;
+                }
+                cell.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints the grid row by row, with cells separated by spaces and rows
+/// separated by newlines.
+///
+/// Formatting options (width, precision, ...) are applied to each cell.
+impl<T: fmt::Display> fmt::Display for Vec2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_with(" ", "\n").fmt(f)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Vec2D, Vec2DErr};
+
+/// A mutable handle to a single cell of a [`Vec2D`].
+///
+/// Created by [`Vec2D::entry`] and [`Vec2D::try_entry`].
+#[derive(Debug)]
+pub struct Entry<'a, T> {
+    cell: &'a mut T,
+    x: usize,
+    y: usize,
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Returns the `(x, y)` coordinates of the cell.
+    pub fn coords(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Returns a shared reference to the cell's value.
+    pub fn get(&self) -> &T {
+        self.cell
+    }
+
+    /// Returns a mutable reference to the cell's value.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.cell
+    }
+
+    /// Converts the entry into a mutable reference to the cell's value, with
+    /// the lifetime of the grid.
+    pub fn into_mut(self) -> &'a mut T {
+        self.cell
+    }
+
+    /// Calls `f` with the cell's value, and returns the entry.
+    pub fn and_modify(self, f: impl FnOnce(&mut T)) -> Self {
+        f(self.cell);
+        self
+    }
+
+    /// Replaces the cell's value, returning the old one.
+    pub fn insert(&mut self, value: T) -> T {
+        core::mem::replace(self.cell, value)
+    }
+}
+
+impl<'a, T> Entry<'a, Option<T>> {
+    /// Returns a mutable reference to the cell's value, storing `default`
+    /// first if the cell is `None`.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.cell.get_or_insert(default)
+    }
+
+    /// Returns a mutable reference to the cell's value, storing the result
+    /// of `f` first if the cell is `None`.
+    pub fn or_insert_with(self, f: impl FnOnce() -> T) -> &'a mut T {
+        self.cell.get_or_insert_with(f)
+    }
+
+    /// Returns a mutable reference to the cell's value, storing
+    /// `T::default()` first if the cell is `None`.
+    pub fn or_default(self) -> &'a mut T
+    where
+        T: Default,
+    {
+        self.cell.get_or_insert_with(T::default)
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Returns an [`Entry`] for the cell at `(x, y)`, for in-place
+    /// read-modify-write operations.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` is out of bounds. See [`Vec2D::try_entry`] for a
+    /// non-panicking version.
+    pub fn entry(&mut self, x: usize, y: usize) -> Entry<'_, T> {
+        Entry {
+            cell: &mut self[(x, y)],
+            x,
+            y,
+        }
+    }
+
+    /// Returns an [`Entry`] for the cell at `(x, y)`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if `x` or `y` is out of bounds.
+    pub fn try_entry(&mut self, x: usize, y: usize) -> Result<Entry<'_, T>, Vec2DErr> {
+        let idx = self.try_index_of(x, y)?;
+        let cell = &mut self.cells[idx];
+
+        Ok(Entry { cell, x, y })
+    }
+}
@@ -0,0 +1,185 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Vec2D;
+
+/// How filters treat the cells beyond the edges of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgePolicy {
+    /// Cells beyond the edges repeat the nearest edge cell.
+    Clamp,
+    /// Cells beyond the edges wrap around to the opposite side.
+    Wrap,
+}
+
+impl EdgePolicy {
+    /// Resolves the coordinate `i + offset` along an axis of length `len`.
+    fn resolve(self, i: usize, offset: isize, len: usize) -> usize {
+        match self {
+            EdgePolicy::Clamp => i.saturating_add_signed(offset).min(len - 1),
+            // Reducing the offset first keeps the sum from overflowing.
+            EdgePolicy::Wrap => (i + offset.rem_euclid(len as isize) as usize) % len,
+        }
+    }
+}
+
+/// Returns the side length `2 * radius + 1` of a filter window.
+///
+/// # Panics
+/// Panics if the side length doesn't fit into an `isize`.
+fn window_side(radius: usize) -> usize {
+    radius
+        .checked_mul(2)
+        .and_then(|diameter| diameter.checked_add(1))
+        .filter(|&side| side <= isize::MAX as usize)
+        .expect("Vec2D filter radius is too large.")
+}
+
+impl<T: Copy + Into<f32>> Vec2D<T> {
+    /// Returns the value at `(x + dx, y + dy)`, resolving coordinates beyond
+    /// the edges with `edges`.
+    fn sample_at(&self, x: usize, y: usize, (dx, dy): (isize, isize), edges: EdgePolicy) -> f32 {
+        let x = edges.resolve(x, dx, self.width);
+        let y = edges.resolve(y, dy, self.height());
+
+        self.cells[y * self.width + x].into()
+    }
+
+    /// Computes a gradient for every cell with `f`, which gets a sampler of
+    /// the neighboring values.
+    fn map_neighborhood(
+        &self,
+        edges: EdgePolicy,
+        f: impl Fn(&dyn Fn(isize, isize) -> f32) -> (f32, f32),
+    ) -> Vec2D<(f32, f32)> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..self.height() {
+            for x in 0..self.width {
+                cells.push(f(&|dx, dy| self.sample_at(x, y, (dx, dy), edges)));
+            }
+        }
+
+        Vec2D {
+            cells,
+            width: self.width,
+        }
+    }
+
+    /// Returns the `(dx, dy)` gradient of every cell, computed with central
+    /// differences.
+    ///
+    /// The neighbors of edge cells are resolved with `edges`.
+    pub fn gradient(&self, edges: EdgePolicy) -> Vec2D<(f32, f32)> {
+        self.map_neighborhood(edges, |at| {
+            ((at(1, 0) - at(-1, 0)) / 2.0, (at(0, 1) - at(0, -1)) / 2.0)
+        })
+    }
+
+    /// Returns the `(dx, dy)` gradient of every cell, computed with the 3x3
+    /// Sobel operator, which is less sensitive to noise than [`Vec2D::gradient`].
+    ///
+    /// The neighbors of edge cells are resolved with `edges`.
+    pub fn sobel(&self, edges: EdgePolicy) -> Vec2D<(f32, f32)> {
+        self.map_neighborhood(edges, |at| {
+            let dx = (at(1, -1) + 2.0 * at(1, 0) + at(1, 1))
+                - (at(-1, -1) + 2.0 * at(-1, 0) + at(-1, 1));
+            let dy = (at(-1, 1) + 2.0 * at(0, 1) + at(1, 1))
+                - (at(-1, -1) + 2.0 * at(0, -1) + at(1, -1));
+
+            (dx, dy)
+        })
+    }
+
+    /// Returns a copy of the grid where every cell is replaced with the mean
+    /// of the `(2 * radius + 1)`x`(2 * radius + 1)` square around it.
+    ///
+    /// The cells beyond the edges are resolved with `edges`. The filter runs
+    /// as two separable sliding-window passes, so its cost doesn't depend on
+    /// `radius`.
+    ///
+    /// # Panics
+    /// Panics if `2 * radius + 1` doesn't fit into an `isize`.
+    pub fn box_blur(&self, radius: usize, edges: EdgePolicy) -> Vec2D<f32> {
+        let (width, height) = (self.width, self.height());
+        let size = window_side(radius) as f32;
+        let r = radius as isize;
+        if height == 0 {
+            return Vec2D {
+                cells: Vec::new(),
+                width,
+            };
+        }
+
+        // Horizontal pass, one row at a time.
+        let mut rows = Vec::with_capacity(self.cells.len());
+        for row in self.iter_rows() {
+            let at =
+                |x: usize, offset: isize| -> f32 { row[edges.resolve(x, offset, width)].into() };
+
+            let mut sum: f32 = (-r..=r).map(|o| at(0, o)).sum();
+            rows.push(sum / size);
+            for x in 1..width {
+                sum += at(x, r) - at(x - 1, -r);
+                rows.push(sum / size);
+            }
+        }
+
+        // Vertical pass, sliding the sums of all columns down at once.
+        let at = |y: usize, offset: isize| edges.resolve(y, offset, height) * width;
+        let mut sums = vec![0.0; width];
+        for o in -r..=r {
+            let row = at(0, o);
+            sums.iter_mut()
+                .zip(&rows[row..row + width])
+                .for_each(|(s, v)| *s += v);
+        }
+
+        let mut cells = Vec::with_capacity(self.cells.len());
+        cells.extend(sums.iter().map(|s| s / size));
+        for y in 1..height {
+            let (add, sub) = (at(y, r), at(y - 1, -r));
+            for (x, s) in sums.iter_mut().enumerate() {
+                *s += rows[add + x] - rows[sub + x];
+            }
+            cells.extend(sums.iter().map(|s| s / size));
+        }
+
+        Vec2D { cells, width }
+    }
+}
+
+impl<T: Ord + Clone> Vec2D<T> {
+    /// Returns a copy of the grid where every cell is replaced with the
+    /// median of the `(2 * radius + 1)`x`(2 * radius + 1)` square around it.
+    ///
+    /// The cells beyond the edges are resolved with `edges`, so every window
+    /// has the same, odd number of cells and a single median.
+    ///
+    /// # Panics
+    /// Panics if `2 * radius + 1` doesn't fit into an `isize`, or if a window
+    /// of that size wouldn't fit into memory.
+    pub fn median_filter(&self, radius: usize, edges: EdgePolicy) -> Vec2D<T> {
+        let (width, height) = (self.width, self.height());
+        let side = window_side(radius);
+        let r = radius as isize;
+
+        let mut window = Vec::with_capacity(side.checked_mul(side).expect("Vector size overflow."));
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                window.clear();
+                for dy in -r..=r {
+                    let row = edges.resolve(y, dy, height) * width;
+                    window
+                        .extend((-r..=r).map(|dx| &self.cells[row + edges.resolve(x, dx, width)]));
+                }
+
+                let mid = window.len() / 2;
+                let (_, median, _) = window.select_nth_unstable(mid);
+                cells.push((*median).clone());
+            }
+        }
+
+        Vec2D { cells, width }
+    }
+}
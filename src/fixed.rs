@@ -0,0 +1,275 @@
+use core::array;
+
+use alloc::vec::Vec;
+
+use crate::{Grid2D, Vec2D, Vec2DErr};
+
+/// A fixed-size, stack-allocated 2D grid, with `W` columns and `H` rows.
+///
+/// This is a companion to [`Vec2D`] for small boards whose dimensions are
+/// known at compile time. Indexing uses the same `(x, y)` coordinates, where
+/// `(0, 0)` is the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Grid<T, const W: usize, const H: usize> {
+    rows: [[T; W]; H],
+}
+
+impl<T: Default, const W: usize, const H: usize> Default for Grid<T, W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Indexes into the grid using `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T, const W: usize, const H: usize> core::ops::Index<(usize, usize)> for Grid<T, W, H> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < W && y < H,
+            // Panic message
+            "Grid index out of bounds: (x: {}, y: {}) in a {}x{} grid.",
+            x,
+            y,
+            W,
+            H
+        );
+
+        &self.rows[y][x]
+    }
+}
+
+/// Mutably indexes into the grid using `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T, const W: usize, const H: usize> core::ops::IndexMut<(usize, usize)> for Grid<T, W, H> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            x < W && y < H,
+            // Panic message
+            "Grid index out of bounds: (x: {}, y: {}) in a {}x{} grid.",
+            x,
+            y,
+            W,
+            H
+        );
+
+        &mut self.rows[y][x]
+    }
+}
+
+/// Moves the grid onto the heap.
+///
+/// A grid with `H == 0` becomes a grid without rows, and one with `W == 0`
+/// becomes a 0x0 grid.
+impl<T, const W: usize, const H: usize> From<Grid<T, W, H>> for Vec2D<T> {
+    fn from(value: Grid<T, W, H>) -> Self {
+        Self {
+            cells: value.rows.into_iter().flatten().collect(),
+            width: W,
+        }
+    }
+}
+
+/// Moves the cells of a heap-allocated grid into a fixed-size one.
+///
+/// # Errors
+/// Returns `Vec2DErr::WidthMismatch` if the widths differ.
+/// Returns `Vec2DErr::HeightMismatch` if the heights differ.
+impl<T, const W: usize, const H: usize> TryFrom<Vec2D<T>> for Grid<T, W, H> {
+    type Error = Vec2DErr;
+
+    fn try_from(value: Vec2D<T>) -> Result<Self, Self::Error> {
+        if value.width != W {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: W,
+                actual: value.width,
+            });
+        } else if value.height() != H {
+            return Err(Vec2DErr::HeightMismatch {
+                expected: H,
+                actual: value.height(),
+            });
+        }
+
+        // The grid holds exactly `W * H` cells.
+        let mut cells = value.cells.into_iter();
+        Ok(Self::from_rows(array::from_fn(|_| {
+            array::from_fn(|_| cells.next().expect("cell count matches"))
+        })))
+    }
+}
+
+impl<T, const W: usize, const H: usize> Grid<T, W, H> {
+    /// Constructs a new grid filled with default values for the type.
+    pub fn new() -> Self
+    where
+        T: Default,
+    {
+        Self::new_with_constructor(T::default)
+    }
+
+    /// Constructs a new grid filled with a cloned default value.
+    pub fn new_with_default(default: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new_with_constructor(|| default.clone())
+    }
+
+    /// Constructs a new grid by calling `constructor` for each cell.
+    pub fn new_with_constructor(constructor: impl Fn() -> T) -> Self {
+        Self::from_rows(array::from_fn(|_| array::from_fn(|_| constructor())))
+    }
+
+    /// Constructs a grid from an array of rows.
+    pub const fn from_rows(rows: [[T; W]; H]) -> Self {
+        Self { rows }
+    }
+
+    /// Consumes the grid and returns its rows.
+    pub fn into_rows(self) -> [[T; W]; H] {
+        self.rows
+    }
+
+    /// Returns a shared slice of all cells in row-major order.
+    #[inline]
+    pub fn cells(&self) -> &[T] {
+        self.rows.as_flattened()
+    }
+
+    /// Returns the width of the grid.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        W
+    }
+
+    /// Returns the height of the grid.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        H
+    }
+
+    /// Converts `(x, y)` coordinates into a linear index.
+    ///
+    /// Returns `None` if the coordinates are out of bounds.
+    pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < W && y < H {
+            Some(y * W + x)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the given index into 2D coordinates (for this grid).
+    ///
+    /// Returns `None` if the coordinates are out of bounds.
+    pub fn coords(&self, idx: usize) -> Option<(usize, usize)> {
+        if idx < W * H {
+            Some((idx % W, idx / W))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a shared reference to the cell at `(x, y)`, if it exists.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`, if it exists.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.rows.get_mut(y)?.get_mut(x)
+    }
+
+    /// Returns a shared reference to row `y`, if it exists.
+    pub fn get_row(&self, y: usize) -> Option<&[T; W]> {
+        self.rows.get(y)
+    }
+
+    /// Returns a mutable reference to row `y`, if it exists.
+    pub fn get_row_mut(&mut self, y: usize) -> Option<&mut [T; W]> {
+        self.rows.get_mut(y)
+    }
+
+    /// Iterates over all cells, yielding their `(x, y)` coordinates and values.
+    pub fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+
+    /// Iterates mutably over all cells, yielding their `(x, y)` coordinates and values.
+    pub fn iter_xy_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.rows.iter_mut().enumerate().flat_map(|(y, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(x, cell)| ((x, y), cell))
+        })
+    }
+
+    /// Iterates over grid rows.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T; W]> {
+        self.rows.iter()
+    }
+
+    /// Iterates over grid rows mutably.
+    pub fn iter_rows_mut(&mut self) -> impl Iterator<Item = &mut [T; W]> {
+        self.rows.iter_mut()
+    }
+
+    /// Applies a function `f` to each cell.
+    pub fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.rows.as_flattened_mut().iter_mut().for_each(f);
+    }
+
+    /// Copies the grid onto the heap, see the `From` implementation for
+    /// [`Vec2D`].
+    pub fn to_vec2d(&self) -> Vec2D<T>
+    where
+        T: Clone,
+    {
+        Vec2D {
+            cells: Vec::from(self.cells()),
+            width: W,
+        }
+    }
+
+    /// Returns an iterator over the von Neumann neighborhood (4-connected)
+    /// of the cell at `(x, y)`.
+    ///
+    /// This includes the north, south, east, and west neighbors.
+    /// Out of bound neighbors are skipped.
+    ///
+    /// The values are represented as `((neighbour's coordinates), neighbor's value)`
+    pub fn neighbors_von_neumann(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Grid2D::neighbors_von_neumann(self, x, y)
+    }
+
+    /// Returns an iterator over the Moore neighborhood (8-connected)
+    /// of the cell at `(x, y)`.
+    ///
+    /// This includes all surrounding cells except the center cell itself.
+    /// Out of bound neighbors are skipped.
+    ///
+    /// The values are represented as `((neighbour's coordinates), neighbor's value)`
+    pub fn neighbors_moore(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Grid2D::neighbors_moore(self, x, y)
+    }
+}
@@ -0,0 +1,47 @@
+use glam::{IVec2, UVec2};
+
+use crate::Vec2D;
+
+/// Indexes into the grid using a `UVec2` as `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::Index<UVec2> for Vec2D<T> {
+    type Output = T;
+
+    fn index(&self, pos: UVec2) -> &Self::Output {
+        &self[(pos.x as usize, pos.y as usize)]
+    }
+}
+
+/// Mutably indexes into the grid using a `UVec2` as `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::IndexMut<UVec2> for Vec2D<T> {
+    fn index_mut(&mut self, pos: UVec2) -> &mut Self::Output {
+        &mut self[(pos.x as usize, pos.y as usize)]
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Returns a shared reference to the cell at the given `IVec2` position,
+    /// if it exists.
+    ///
+    /// Negative coordinates are treated as out of bounds.
+    pub fn get_ivec(&self, pos: IVec2) -> Option<&T> {
+        let x = usize::try_from(pos.x).ok()?;
+        let y = usize::try_from(pos.y).ok()?;
+        self.get(x, y)
+    }
+
+    /// Returns a mutable reference to the cell at the given `IVec2` position,
+    /// if it exists.
+    ///
+    /// Negative coordinates are treated as out of bounds.
+    pub fn get_ivec_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        let x = usize::try_from(pos.x).ok()?;
+        let y = usize::try_from(pos.y).ok()?;
+        self.get_mut(x, y)
+    }
+}
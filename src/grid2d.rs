@@ -0,0 +1,203 @@
+#[cfg(doc)]
+use crate::CellGrid;
+use crate::{BitGrid, Grid, Vec2D, View};
+
+/// Offsets of the von Neumann neighborhood, in the order they are yielded.
+const VON_NEUMANN: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the Moore neighborhood, in the order they are yielded.
+const MOORE: [(isize, isize); 8] = [
+    // Top row
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    // Middle row
+    (-1, 0),
+    /* (0, 0), */
+    (1, 0),
+    // Bottom row
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A read-only 2D grid, indexed with `(x, y)` coordinates where `(0, 0)` is
+/// the top-left corner.
+///
+/// This is implemented by [`Vec2D`], [`Grid`], [`View`], [`BitGrid`],
+/// [`CellGrid`], and `SparseGrid2D`, so algorithms (pathfinding, flood fill,
+/// ...) can be written once against the trait.
+pub trait Grid2D {
+    /// The type of a single cell.
+    type Cell;
+
+    /// Returns the width of the grid.
+    fn width(&self) -> usize;
+
+    /// Returns the height of the grid.
+    fn height(&self) -> usize;
+
+    /// Returns a shared reference to the cell at `(x, y)`, if it exists.
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Cell>;
+
+    /// Returns `true` if `(x, y)` lies within the grid.
+    fn contains_coords(&self, x: usize, y: usize) -> bool {
+        x < self.width() && y < self.height()
+    }
+
+    /// Iterates over all cells in row-major order, yielding their `(x, y)`
+    /// coordinates and values.
+    fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &Self::Cell)> {
+        let width = self.width();
+        (0..self.height())
+            .flat_map(move |y| (0..width).map(move |x| (x, y)))
+            .filter_map(|(x, y)| Some(((x, y), self.get(x, y)?)))
+    }
+
+    /// Returns an iterator over the von Neumann neighborhood (4-connected)
+    /// of the cell at `(x, y)`, skipping out of bound neighbors.
+    fn neighbors_von_neumann(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &Self::Cell)> {
+        neighbors(self, x, y, &VON_NEUMANN)
+    }
+
+    /// Returns an iterator over the Moore neighborhood (8-connected) of the
+    /// cell at `(x, y)`, skipping out of bound neighbors.
+    fn neighbors_moore(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &Self::Cell)> {
+        neighbors(self, x, y, &MOORE)
+    }
+}
+
+/// A 2D grid whose cells can be mutated.
+pub trait Grid2DMut: Grid2D {
+    /// Returns a mutable reference to the cell at `(x, y)`, if it exists.
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Cell>;
+}
+
+fn neighbors<'a, G: Grid2D + ?Sized>(
+    grid: &'a G,
+    x: usize,
+    y: usize,
+    offsets: &'static [(isize, isize)],
+) -> impl Iterator<Item = ((usize, usize), &'a G::Cell)> {
+    offsets.iter().filter_map(move |&(dx, dy)| {
+        let nx = x.checked_add_signed(dx)?;
+        let ny = y.checked_add_signed(dy)?;
+
+        Some(((nx, ny), grid.get(nx, ny)?))
+    })
+}
+
+impl<G: Grid2D + ?Sized> Grid2D for &G {
+    type Cell = G::Cell;
+
+    fn width(&self) -> usize {
+        G::width(self)
+    }
+
+    fn height(&self) -> usize {
+        G::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Cell> {
+        G::get(self, x, y)
+    }
+}
+
+impl<T> Grid2D for Vec2D<T> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        Vec2D::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Vec2D::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        Vec2D::get(self, x, y)
+    }
+
+    fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Vec2D::iter_xy(self)
+    }
+}
+
+impl<T> Grid2DMut for Vec2D<T> {
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        Vec2D::get_mut(self, x, y)
+    }
+}
+
+impl<T, const W: usize, const H: usize> Grid2D for Grid<T, W, H> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        W
+    }
+
+    fn height(&self) -> usize {
+        H
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        Grid::get(self, x, y)
+    }
+
+    fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Grid::iter_xy(self)
+    }
+}
+
+impl<T, const W: usize, const H: usize> Grid2DMut for Grid<T, W, H> {
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        Grid::get_mut(self, x, y)
+    }
+}
+
+impl<T> Grid2D for View<'_, T> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        View::width(self)
+    }
+
+    fn height(&self) -> usize {
+        View::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        View::get(self, x, y)
+    }
+
+    fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        View::iter_xy(self)
+    }
+}
+
+impl Grid2D for BitGrid {
+    type Cell = bool;
+
+    fn width(&self) -> usize {
+        BitGrid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        BitGrid::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&bool> {
+        match BitGrid::get(self, x, y)? {
+            true => Some(&true),
+            false => Some(&false),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use crate::{GridPatch, Vec2D, Vec2DErr};
+
+/// An undo/redo history for a [`Vec2D`], storing each step as a pair of
+/// patches rather than full snapshots.
+///
+/// The history doesn't own the grid, so the same grid has to be passed to
+/// every call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridHistory<T> {
+    undo: VecDeque<Step<T>>,
+    redo: Vec<Step<T>>,
+    depth: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step<T> {
+    forward: GridPatch<T>,
+    reverse: GridPatch<T>,
+}
+
+impl<T: Clone> GridHistory<T> {
+    /// Constructs an empty history, which keeps at most `depth` undo steps.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            depth,
+        }
+    }
+
+    /// Returns the maximum number of undo steps kept.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns `true` if there is a step to undo.
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is a step to redo.
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Discards all recorded steps.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn push(&mut self, step: Step<T>) {
+        self.redo.clear();
+        self.undo.push_back(step);
+        while self.undo.len() > self.depth {
+            self.undo.pop_front();
+        }
+    }
+
+    /// Applies `patch` to `grid` and records it as a new undo step.
+    /// Any undone steps can no longer be redone.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if any of the changes is out of
+    /// bounds. In that case, neither the grid nor the history are changed.
+    pub fn apply(&mut self, grid: &mut Vec2D<T>, patch: GridPatch<T>) -> Result<(), Vec2DErr> {
+        let reverse = patch.apply_reversible(grid)?;
+        self.push(Step {
+            forward: patch,
+            reverse,
+        });
+        Ok(())
+    }
+
+    /// Records the changes between `before` and `after` as a new undo step,
+    /// for edits that were made to the grid directly.
+    /// Any undone steps can no longer be redone.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Vec2D::diff`].
+    pub fn record(&mut self, before: &Vec2D<T>, after: &Vec2D<T>) -> Result<(), Vec2DErr>
+    where
+        T: PartialEq,
+    {
+        let forward = GridPatch::from_diff(before, after)?;
+        let reverse = GridPatch::from_diff(after, before)?;
+        if !forward.is_empty() {
+            self.push(Step { forward, reverse });
+        }
+        Ok(())
+    }
+
+    /// Reverts the most recent step. Returns `false` if there was nothing
+    /// to undo.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the grid has shrunk since the step
+    /// was recorded. In that case, the step is kept.
+    pub fn undo(&mut self, grid: &mut Vec2D<T>) -> Result<bool, Vec2DErr> {
+        let Some(step) = self.undo.pop_back() else {
+            return Ok(false);
+        };
+
+        match step.reverse.apply(grid) {
+            Ok(()) => {
+                self.redo.push(step);
+                Ok(true)
+            }
+            Err(err) => {
+                self.undo.push_back(step);
+                Err(err)
+            }
+        }
+    }
+
+    /// Re-applies the most recently undone step. Returns `false` if there
+    /// was nothing to redo.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the grid has shrunk since the step
+    /// was recorded. In that case, the step is kept.
+    pub fn redo(&mut self, grid: &mut Vec2D<T>) -> Result<bool, Vec2DErr> {
+        let Some(step) = self.redo.pop() else {
+            return Ok(false);
+        };
+
+        match step.forward.apply(grid) {
+            Ok(()) => {
+                self.undo.push_back(step);
+                Ok(true)
+            }
+            Err(err) => {
+                self.redo.push(step);
+                Err(err)
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use core::ops::Deref;
+
+use alloc::vec::Vec;
+
+use image::{ImageBuffer, Pixel};
+
+use crate::{Vec2D, Vec2DErr};
+
+/// Converts a grid of pixels into an image buffer with the same dimensions.
+///
+/// # Errors
+/// Returns `Vec2DErr::TooLarge` if the grid's width or height doesn't
+/// fit into a `u32`.
+impl<P: Pixel> TryFrom<Vec2D<P>> for ImageBuffer<P, Vec<P::Subpixel>> {
+    type Error = Vec2DErr;
+
+    fn try_from(value: Vec2D<P>) -> Result<Self, Self::Error> {
+        let width = u32::try_from(value.width()).map_err(|_| Vec2DErr::TooLarge)?;
+        let height = u32::try_from(value.height()).map_err(|_| Vec2DErr::TooLarge)?;
+
+        let mut subpixels = Vec::with_capacity(value.cells().len() * P::CHANNEL_COUNT as usize);
+        for pixel in value.cells() {
+            subpixels.extend_from_slice(pixel.channels());
+        }
+
+        // The buffer holds exactly `width * height` pixels.
+        Ok(ImageBuffer::from_raw(width, height, subpixels).expect("buffer size matches"))
+    }
+}
+
+/// Converts an image buffer into a grid of pixels with the same dimensions.
+///
+/// # Errors
+/// Returns `Vec2DErr::ZeroWidth` if the image's width is 0.
+/// Returns `Vec2DErr::ZeroHeight` if the image's height is 0.
+impl<P, Container> TryFrom<ImageBuffer<P, Container>> for Vec2D<P>
+where
+    P: Pixel,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    type Error = Vec2DErr;
+
+    fn try_from(value: ImageBuffer<P, Container>) -> Result<Self, Self::Error> {
+        if value.width() == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        } else if value.height() == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        }
+
+        Ok(Self {
+            cells: value.pixels().copied().collect(),
+            width: value.width() as usize,
+        })
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Rect, Vec2D, Vec2DErr};
+
+impl<T> Vec2D<T> {
+    /// Iterates over all diagonals running from the top-left towards the
+    /// bottom-right, each yielded as an iterator of coordinates and values.
+    ///
+    /// Diagonals are ordered from the bottom-left corner to the top-right
+    /// corner, so the first and last ones contain a single cell.
+    pub fn iter_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = ((usize, usize), &T)>> {
+        let (width, height) = (self.width, self.height());
+
+        // A grid without rows has no diagonals.
+        let count = if height == 0 { 0 } else { width + height - 1 };
+
+        (0..count).map(move |d| {
+            // The first cell of the diagonal lies on the left or top edge.
+            let (x, y) = if d < height {
+                (0, height - 1 - d)
+            } else {
+                (d + 1 - height, 0)
+            };
+            let len = (width - x).min(height - y);
+
+            (0..len).map(move |i| ((x + i, y + i), &self.cells[(y + i) * width + x + i]))
+        })
+    }
+
+    /// Iterates over all anti-diagonals running from the top-right towards
+    /// the bottom-left, each yielded as an iterator of coordinates and
+    /// values.
+    ///
+    /// Anti-diagonals are ordered from the top-left corner to the
+    /// bottom-right corner, so the first and last ones contain a single cell.
+    pub fn iter_anti_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = ((usize, usize), &T)>> {
+        let (width, height) = (self.width, self.height());
+
+        // A grid without rows has no anti-diagonals.
+        let count = if height == 0 { 0 } else { width + height - 1 };
+
+        (0..count).map(move |s| {
+            // The first cell of the anti-diagonal lies on the top or right
+            // edge.
+            let (x, y) = if s < width {
+                (s, 0)
+            } else {
+                (width - 1, s + 1 - width)
+            };
+            let len = (x + 1).min(height - y);
+
+            (0..len).map(move |i| ((x - i, y + i), &self.cells[(y + i) * width + x - i]))
+        })
+    }
+
+    /// Iterates over all cells in clockwise spiral order, starting at the
+    /// top-left corner and moving inwards, yielding their `(x, y)`
+    /// coordinates and values.
+    pub fn iter_spiral(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let (width, height) = (self.width, self.height());
+        let layers = width.min(height).div_ceil(2);
+
+        (0..layers)
+            .flat_map(move |l| {
+                let (left, top) = (l, l);
+                let (right, bottom) = (width - 1 - l, height - 1 - l);
+
+                // The bottom row and left column are skipped for layers that
+                // are a single row or column thick, which the top row or right
+                // column already covered.
+                let bottom_row = if bottom > top { left..right } else { 0..0 };
+                let left_col = if right > left { top + 1..bottom } else { 0..0 };
+
+                (left..=right)
+                    .map(move |x| (x, top))
+                    .chain((top + 1..=bottom).map(move |y| (right, y)))
+                    .chain(bottom_row.rev().map(move |x| (x, bottom)))
+                    .chain(left_col.rev().map(move |y| (left, y)))
+            })
+            .map(move |(x, y)| ((x, y), &self.cells[y * width + x]))
+    }
+
+    /// Returns `true` if `(x, y)` lies on the outermost ring of cells.
+    pub fn is_border(&self, x: usize, y: usize) -> bool {
+        let (width, height) = (self.width, self.height());
+
+        x < width && y < height && (x == 0 || y == 0 || x == width - 1 || y == height - 1)
+    }
+
+    /// Iterates over the outermost ring of cells in row-major order, yielding
+    /// their `(x, y)` coordinates and values.
+    pub fn iter_border(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let (width, height) = (self.width, self.height());
+
+        self.cells
+            .chunks_exact(width.max(1))
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .step_by(border_step(width, height, y))
+                    .map(move |(x, cell)| ((x, y), cell))
+            })
+    }
+
+    /// Iterates over the outermost ring of cells in row-major order, yielding
+    /// their `(x, y)` coordinates and mutable references to their values.
+    pub fn iter_border_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let (width, height) = (self.width, self.height());
+
+        self.cells
+            .chunks_exact_mut(width.max(1))
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row.iter_mut()
+                    .enumerate()
+                    .step_by(border_step(width, height, y))
+                    .map(move |(x, cell)| ((x, y), cell))
+            })
+    }
+
+    /// Iterates over the cells in `rect`, in row-major order, yielding their
+    /// `(x, y)` coordinates (in the grid) and values.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `rect.width == 0`.
+    ///
+    /// Returns `Vec2DErr::ZeroHeight` if `rect.height == 0`.
+    ///
+    /// Returns `Vec2DErr::OutOfBounds` if the rectangle doesn't fit into the
+    /// grid.
+    pub fn iter_rect(
+        &self,
+        rect: Rect,
+    ) -> Result<impl Iterator<Item = ((usize, usize), &T)>, Vec2DErr> {
+        self.check_rect(rect)?;
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = rect;
+
+        Ok(self
+            .cells
+            .chunks_exact(self.width)
+            .enumerate()
+            .skip(y)
+            .take(height)
+            .flat_map(move |(row_y, row)| {
+                row[x..x + width]
+                    .iter()
+                    .enumerate()
+                    .map(move |(dx, cell)| ((x + dx, row_y), cell))
+            }))
+    }
+
+    /// Iterates over the cells in `rect`, in row-major order, yielding their
+    /// `(x, y)` coordinates (in the grid) and mutable references to their
+    /// values.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `rect.width == 0`.
+    ///
+    /// Returns `Vec2DErr::ZeroHeight` if `rect.height == 0`.
+    ///
+    /// Returns `Vec2DErr::OutOfBounds` if the rectangle doesn't fit into the
+    /// grid.
+    pub fn iter_rect_mut(
+        &mut self,
+        rect: Rect,
+    ) -> Result<impl Iterator<Item = ((usize, usize), &mut T)>, Vec2DErr> {
+        self.check_rect(rect)?;
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = rect;
+
+        Ok(self
+            .cells
+            .chunks_exact_mut(self.width)
+            .enumerate()
+            .skip(y)
+            .take(height)
+            .flat_map(move |(row_y, row)| {
+                row[x..x + width]
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(dx, cell)| ((x + dx, row_y), cell))
+            }))
+    }
+}
+
+/// Returns the step between the border cells of row `y`: every cell for the
+/// first and last rows, only the first and last cell otherwise.
+fn border_step(width: usize, height: usize, y: usize) -> usize {
+    if y == 0 || y == height - 1 || width == 1 {
+        1
+    } else {
+        width - 1
+    }
+}
@@ -1,31 +1,161 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+mod atomic;
+mod bitgrid;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_interop;
+mod cell_grid;
+mod chunked;
+mod contour;
+mod coord;
+#[cfg(feature = "csv")]
+mod csv_io;
+mod cursor;
+mod direction;
+mod display;
+mod entry;
+mod filter;
+mod fixed;
+#[cfg(feature = "glam")]
+mod glam_interop;
+mod grid2d;
+mod history;
+#[cfg(feature = "image")]
+mod image_interop;
+mod iter;
+mod mask;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
+#[cfg(feature = "std")]
+mod netpbm;
+mod ops;
+#[cfg(feature = "std")]
+mod parallel;
+mod parse;
+mod patch;
+mod query;
+mod rect;
+mod reduce;
+mod region;
+mod rle;
+mod sample;
+#[cfg(feature = "serialize")]
+mod serde_impl;
+#[cfg(feature = "serialize")]
+pub mod serde_rows;
+mod snapshot;
+#[cfg(feature = "std")]
+mod sparse;
 mod test;
+mod tracked;
+mod transform;
+mod view;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub use atomic::AtomicCell;
+pub use bitgrid::BitGrid;
+pub use cell_grid::CellGrid;
+pub use chunked::ChunkedGrid;
+pub use coord::Coord;
+#[cfg(feature = "csv")]
+pub use csv_io::CsvError;
+pub use cursor::GridCursor;
+pub use direction::Direction;
+pub use display::DisplayWith;
+pub use entry::Entry;
+pub use filter::EdgePolicy;
+pub use fixed::Grid;
+pub use grid2d::{Grid2D, Grid2DMut};
+pub use history::GridHistory;
+pub use parse::ParseError;
+pub use patch::GridPatch;
+pub use rect::Rect;
+pub use region::RegionStats;
+pub use sample::Pooling;
+pub use snapshot::{SnapshotCell, SnapshotError};
+#[cfg(feature = "std")]
+pub use sparse::SparseGrid2D;
+pub use tracked::TrackedVec2D;
+pub use transform::ResizePolicy;
+pub use view::View;
 
 /// A 2D, row-major grid backed by a contiguous `Vec<T>`.
 ///
 /// Elements are stored left-to-right, top-to-bottom. Indexing is performed
 /// using `(x, y)` coordinates, where `(0, 0)` is the top-left corner.
-#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+///
+/// # Serialization
+/// With the `serialize` feature, grids are serialized using a stable,
+/// versioned schema, independent of the internal layout:
+///
+/// | Field     | Description                              |
+/// |-----------|------------------------------------------|
+/// | `version` | The schema version, currently `1`.       |
+/// | `width`   | The width of the grid.                   |
+/// | `height`  | The height of the grid.                  |
+/// | `cells`   | All cells in row-major order.            |
+///
+/// Payloads without a `version` field are read as the legacy `cells` +
+/// `width` representation written before the schema was versioned. See
+/// `serde_rows` for an alternate, nested-rows representation.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Vec2D<T> {
     cells: Vec<T>,
     width: usize,
 }
 
-#[derive(Debug)]
+/// The error type of all fallible grid operations.
+///
+/// New variants may be added in the future, so matches on it need a
+/// wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Vec2DErr {
+    /// A byte buffer of length `len` doesn't hold a whole number of cells of
+    /// `cell_size` bytes.
+    ByteLengthMismatch { len: usize, cell_size: usize },
+    /// The source collection has no elements.
     EmptySource,
-    OutOfBounds,
-    WidthMismatch(usize, usize),
+    /// A height (or a column's length) differs from the expected one.
+    HeightMismatch { expected: usize, actual: usize },
+    /// The cell at `(x, y)` is outside of a `width`x`height` grid.
+    OutOfBounds {
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    },
+    /// Row `y` is outside of a grid with `height` rows.
+    RowOutOfBounds { y: usize, height: usize },
+    /// Column `x` is outside of a grid with `width` columns.
+    ColOutOfBounds { x: usize, width: usize },
+    /// The grid's dimensions can't be represented in the target type.
+    TooLarge,
+    /// A width (or a row's length) differs from, or isn't a multiple of, the
+    /// expected one.
+    WidthMismatch { expected: usize, actual: usize },
+    /// The height is 0.
     ZeroHeight,
+    /// The width is 0.
     ZeroWidth,
 }
+#[cfg(feature = "std")]
 impl std::error::Error for Vec2DErr {}
 
 /// Indexes into the grid using `(x, y)` coordinates.
 ///
 /// # Panics
 /// Panics if `x` or `y` is out of bounds.
-impl<T> std::ops::Index<(usize, usize)> for Vec2D<T> {
+impl<T> core::ops::Index<(usize, usize)> for Vec2D<T> {
     type Output = T;
 
     fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
@@ -47,7 +177,7 @@ impl<T> std::ops::Index<(usize, usize)> for Vec2D<T> {
 ///
 /// # Panics
 /// Panics if `x` or `y` is out of bounds.
-impl<T> std::ops::IndexMut<(usize, usize)> for Vec2D<T> {
+impl<T> core::ops::IndexMut<(usize, usize)> for Vec2D<T> {
     fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
         assert!(
             x < self.width && y < self.height(),
@@ -63,6 +193,86 @@ impl<T> std::ops::IndexMut<(usize, usize)> for Vec2D<T> {
     }
 }
 
+/// Indexes into the grid using `[x, y]` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::Index<[usize; 2]> for Vec2D<T> {
+    type Output = T;
+
+    fn index(&self, [x, y]: [usize; 2]) -> &Self::Output {
+        &self[(x, y)]
+    }
+}
+
+/// Mutably indexes into the grid using `[x, y]` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::IndexMut<[usize; 2]> for Vec2D<T> {
+    fn index_mut(&mut self, [x, y]: [usize; 2]) -> &mut Self::Output {
+        &mut self[(x, y)]
+    }
+}
+
+/// Indexes into the grid using a reference to `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::Index<&(usize, usize)> for Vec2D<T> {
+    type Output = T;
+
+    fn index(&self, &pos: &(usize, usize)) -> &Self::Output {
+        &self[pos]
+    }
+}
+
+/// Mutably indexes into the grid using a reference to `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::IndexMut<&(usize, usize)> for Vec2D<T> {
+    fn index_mut(&mut self, &pos: &(usize, usize)) -> &mut Self::Output {
+        &mut self[pos]
+    }
+}
+
+/// Returns row `y` as a slice, so cells can also be accessed with
+/// `grid[y][x]`.
+///
+/// # Panics
+/// Panics if `y` is out of bounds.
+impl<T> core::ops::Index<usize> for Vec2D<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &Self::Output {
+        let height = self.height();
+        self.get_row(y).unwrap_or_else(|| {
+            panic!(
+                "Vec2D row index out of bounds: row {} in a grid of height {}.",
+                y, height
+            )
+        })
+    }
+}
+
+/// Returns row `y` as a mutable slice, so cells can also be accessed with
+/// `grid[y][x]`.
+///
+/// # Panics
+/// Panics if `y` is out of bounds.
+impl<T> core::ops::IndexMut<usize> for Vec2D<T> {
+    fn index_mut(&mut self, y: usize) -> &mut Self::Output {
+        let height = self.height();
+        self.get_row_mut(y).unwrap_or_else(|| {
+            panic!(
+                "Vec2D row index out of bounds: row {} in a grid of height {}.",
+                y, height
+            )
+        })
+    }
+}
+
 /// Consumes the grid and returns the underlying storage vector.
 impl<T> From<Vec2D<T>> for Vec<T> {
     fn from(value: Vec2D<T>) -> Self {
@@ -81,7 +291,7 @@ impl<T> TryFrom<(Vec<T>, usize)> for Vec2D<T> {
 /// Consumes the grid and iterates over all elements in row-major order.
 impl<T> IntoIterator for Vec2D<T> {
     type Item = T;
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cells.into_iter()
@@ -91,7 +301,7 @@ impl<T> IntoIterator for Vec2D<T> {
 /// Iterates over shared references to all elements in row-major order.
 impl<'a, T> IntoIterator for &'a Vec2D<T> {
     type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+    type IntoIter = core::slice::Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cells.iter()
@@ -101,13 +311,98 @@ impl<'a, T> IntoIterator for &'a Vec2D<T> {
 /// Iterates over mutable references to all elements in row-major order.
 impl<'a, T> IntoIterator for &'a mut Vec2D<T> {
     type Item = &'a mut T;
-    type IntoIter = std::slice::IterMut<'a, T>;
+    type IntoIter = core::slice::IterMut<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cells.iter_mut()
     }
 }
 
+/// Constructs an empty 0x0 grid. Its width is set by the first row appended
+/// to it.
+impl<T> Default for Vec2D<T> {
+    fn default() -> Self {
+        Self {
+            cells: Vec::new(),
+            width: 0,
+        }
+    }
+}
+
+/// Appends each vector as a new row at the bottom of the grid.
+///
+/// If the grid is 0x0, the first row sets its width.
+///
+/// Since `Vec2D` has an inherent `extend` method, the trait has to be called
+/// explicitly: `Extend::extend(&mut grid, rows)`.
+///
+/// # Panics
+/// Panics if the length of any row is not equal to the grid's width, or if
+/// the rows wouldn't fit into memory.
+impl<T> Extend<Vec<T>> for Vec2D<T> {
+    fn extend<I: IntoIterator<Item = Vec<T>>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let additional = iter
+            .size_hint()
+            .0
+            .checked_mul(self.width)
+            .expect("Vector size overflow.");
+        self.cells.reserve(additional);
+
+        for row in iter {
+            self.adopt_width(row.len());
+            assert_eq!(
+                row.len(),
+                self.width,
+                // Panic message
+                "Vec2D row width mismatch: row of length {} in a grid of width {}.",
+                row.len(),
+                self.width
+            );
+
+            self.cells.extend(row);
+        }
+
+        self.debug_validate();
+    }
+}
+
+/// Appends each array as a new row at the bottom of the grid.
+///
+/// If the grid is 0x0, its width is set to `N`.
+///
+/// Since `Vec2D` has an inherent `extend` method, the trait has to be called
+/// explicitly: `Extend::extend(&mut grid, rows)`.
+///
+/// # Panics
+/// Panics if any row is yielded and `N` is not equal to the grid's width, or
+/// if the rows wouldn't fit into memory.
+impl<T, const N: usize> Extend<[T; N]> for Vec2D<T> {
+    fn extend<I: IntoIterator<Item = [T; N]>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let additional = iter
+            .size_hint()
+            .0
+            .checked_mul(N)
+            .expect("Vector size overflow.");
+        self.cells.reserve(additional);
+
+        for row in iter {
+            self.adopt_width(N);
+            assert_eq!(
+                N, self.width,
+                // Panic message
+                "Vec2D row width mismatch: row of length {} in a grid of width {}.",
+                N, self.width
+            );
+
+            self.cells.extend(row);
+        }
+
+        self.debug_validate();
+    }
+}
+
 /// Borrows the underlying storage as a slice.
 impl<T> AsRef<[T]> for Vec2D<T> {
     fn as_ref(&self) -> &[T] {
@@ -128,20 +423,20 @@ impl<T> Vec2D<T> {
     /// # Errors
     /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
     /// Returns `Vec2DErr::ZeroHeight` if `height == 0`.
+    /// Returns `Vec2DErr::TooLarge` if the cells wouldn't fit into memory.
     pub fn new(width: usize, height: usize) -> Result<Self, Vec2DErr>
     where
         T: Default + Clone,
     {
-        assert!(width * height < usize::MAX, "Vector size overflow.");
-
         if width == 0 {
             return Err(Vec2DErr::ZeroWidth);
         } else if height == 0 {
             return Err(Vec2DErr::ZeroHeight);
         }
+        let len = Self::checked_len(width, height)?;
 
         Ok(Self {
-            cells: vec![T::default(); width * height],
+            cells: vec![T::default(); len],
             width,
         })
     }
@@ -151,20 +446,20 @@ impl<T> Vec2D<T> {
     /// # Errors
     /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
     /// Returns `Vec2DErr::ZeroHeight` if `height == 0`.
+    /// Returns `Vec2DErr::TooLarge` if the cells wouldn't fit into memory.
     pub fn new_with_default(width: usize, height: usize, default: T) -> Result<Self, Vec2DErr>
     where
         T: Clone,
     {
-        assert!(width * height < usize::MAX, "Vector size overflow.");
-
         if width == 0 {
             return Err(Vec2DErr::ZeroWidth);
         } else if height == 0 {
             return Err(Vec2DErr::ZeroHeight);
         }
+        let len = Self::checked_len(width, height)?;
 
         Ok(Self {
-            cells: vec![default; width * height],
+            cells: vec![default; len],
             width,
         })
     }
@@ -174,21 +469,21 @@ impl<T> Vec2D<T> {
     /// # Errors
     /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
     /// Returns `Vec2DErr::ZeroHeight` if `height == 0`.
+    /// Returns `Vec2DErr::TooLarge` if the cells wouldn't fit into memory.
     pub fn new_with_constructor(
         width: usize,
         height: usize,
         constructor: impl Fn() -> T,
     ) -> Result<Self, Vec2DErr> {
-        assert!(width * height < usize::MAX, "Vector size overflow.");
-
         if width == 0 {
             return Err(Vec2DErr::ZeroWidth);
         } else if height == 0 {
             return Err(Vec2DErr::ZeroHeight);
         }
+        let len = Self::checked_len(width, height)?;
 
-        let mut cells = Vec::with_capacity(width * height);
-        for _ in 0..(width * height) {
+        let mut cells = Vec::with_capacity(len);
+        for _ in 0..len {
             cells.push(constructor());
         }
         Ok(Self { cells, width })
@@ -202,8 +497,8 @@ impl<T> Vec2D<T> {
     ///
     /// Returns `Vec2DErr::EmptySource` if the source vector is empty.
     ///
-    /// Returns `Vec2DErr::WidthMismatch(vec.len(), width)` if the source
-    /// vector's length is not divisible by the given `width`.
+    /// Returns `Vec2DErr::WidthMismatch` if the source vector's length is not
+    /// divisible by the given `width`.
     pub fn from_vec(vec: Vec<T>, width: usize) -> Result<Self, Vec2DErr> {
         if width == 0 {
             return Err(Vec2DErr::ZeroWidth);
@@ -211,12 +506,118 @@ impl<T> Vec2D<T> {
             return Err(Vec2DErr::EmptySource);
         }
         if !vec.len().is_multiple_of(width) {
-            return Err(Vec2DErr::WidthMismatch(vec.len(), width));
+            return Err(Vec2DErr::WidthMismatch {
+                expected: width,
+                actual: vec.len(),
+            });
         }
 
         Ok(Self { cells: vec, width })
     }
 
+    /// Constructs a grid of the given width with no rows, to which rows can
+    /// be appended later.
+    ///
+    /// For a 0x0 grid, whose width is set by the first appended row, see
+    /// `Vec2D::default`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
+    pub fn empty(width: usize) -> Result<Self, Vec2DErr> {
+        if width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        }
+
+        Ok(Self {
+            cells: Vec::new(),
+            width,
+        })
+    }
+
+    /// Constructs an empty grid of the given width, with space reserved for
+    /// at least `rows` rows.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
+    ///
+    /// Returns `Vec2DErr::TooLarge` if the reserved cells wouldn't fit into
+    /// memory.
+    pub fn with_capacity_rows(width: usize, rows: usize) -> Result<Self, Vec2DErr> {
+        if width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        }
+
+        Ok(Self {
+            cells: Vec::with_capacity(Self::checked_len(width, rows)?),
+            width,
+        })
+    }
+
+    /// Reserves space for at least `additional` more rows.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows, or exceeds `isize::MAX` bytes.
+    pub fn reserve_rows(&mut self, additional: usize) {
+        let additional = additional
+            .checked_mul(self.width)
+            .expect("Vector size overflow.");
+        self.cells.reserve(additional);
+    }
+
+    /// Returns the number of rows the grid can hold without reallocating.
+    pub fn capacity_rows(&self) -> usize {
+        self.cells.capacity().checked_div(self.width).unwrap_or(0)
+    }
+
+    /// Shrinks the capacity of the underlying storage as much as possible,
+    /// releasing memory left over from appending or truncating rows.
+    pub fn shrink_to_fit(&mut self) {
+        self.cells.shrink_to_fit();
+    }
+
+    /// Reassembles a grid from the parts returned by `into_raw_parts`,
+    /// without copying.
+    ///
+    /// Unlike `from_vec`, this accepts an empty vector, which results in a
+    /// grid without rows, or a 0x0 grid if `width == 0`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `width == 0` but `cells` isn't empty.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the length of `cells` is not a
+    /// multiple of `width`.
+    pub fn from_raw_parts(cells: Vec<T>, width: usize) -> Result<Self, Vec2DErr> {
+        let grid = Self { cells, width };
+        grid.validate()?;
+
+        Ok(grid)
+    }
+
+    /// Consumes the grid and returns its underlying storage vector and its
+    /// width.
+    pub fn into_raw_parts(self) -> (Vec<T>, usize) {
+        (self.cells, self.width)
+    }
+
+    /// Constructs a grid from a boxed slice, without copying.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
+    ///
+    /// Returns `Vec2DErr::EmptySource` if `slice` is empty.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the source slice's length is not a
+    /// multiple of `width`.
+    pub fn from_boxed(slice: Box<[T]>, width: usize) -> Result<Self, Vec2DErr> {
+        Self::from_vec(slice.into_vec(), width)
+    }
+
+    /// Consumes the grid and returns its cells as a boxed slice, along with
+    /// its width, dropping any excess capacity.
+    pub fn into_boxed_cells(self) -> (Box<[T]>, usize) {
+        (self.cells.into_boxed_slice(), self.width)
+    }
+
     /// Constructs a grid from an existing slice and a given width.
     /// The slice length must be a multiple of `width`.
     ///
@@ -225,8 +626,8 @@ impl<T> Vec2D<T> {
     ///
     /// Returns `Vec2DErr::EmptySource` if the source slice is empty.
     ///
-    /// Returns `Vec2DErr::WidthMismatch(slice.len(), width)` if the source
-    /// vector's length is not divisible by the given `width`.
+    /// Returns `Vec2DErr::WidthMismatch` if the source vector's length is not
+    /// divisible by the given `width`.
     pub fn from_slice(slice: &[T], width: usize) -> Result<Self, Vec2DErr>
     where
         T: Clone,
@@ -237,7 +638,10 @@ impl<T> Vec2D<T> {
             return Err(Vec2DErr::EmptySource);
         }
         if !slice.len().is_multiple_of(width) {
-            return Err(Vec2DErr::WidthMismatch(slice.len(), width));
+            return Err(Vec2DErr::WidthMismatch {
+                expected: width,
+                actual: slice.len(),
+            });
         }
 
         Ok(Self {
@@ -246,13 +650,106 @@ impl<T> Vec2D<T> {
         })
     }
 
+    /// Constructs a grid from a vector of rows.
+    /// All rows must have the same length, which becomes the grid's width.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::EmptySource` if there are no rows.
+    ///
+    /// Returns `Vec2DErr::ZeroWidth` if the rows are empty.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if any row's length differs from the
+    /// length of the first row.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Vec2DErr> {
+        let Some(width) = rows.first().map(Vec::len) else {
+            return Err(Vec2DErr::EmptySource);
+        };
+        if width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        }
+        if let Some(row) = rows.iter().find(|row| row.len() != width) {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: width,
+                actual: row.len(),
+            });
+        }
+
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in rows {
+            cells.extend(row);
+        }
+        Ok(Self { cells, width })
+    }
+
+    /// Constructs a grid from a vector of columns.
+    /// All columns must have the same length, which becomes the grid's height.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::EmptySource` if there are no columns.
+    ///
+    /// Returns `Vec2DErr::ZeroHeight` if the columns are empty.
+    ///
+    /// Returns `Vec2DErr::HeightMismatch` if any column's length differs from
+    /// the length of the first column.
+    pub fn from_columns(cols: Vec<Vec<T>>) -> Result<Self, Vec2DErr> {
+        let Some(height) = cols.first().map(Vec::len) else {
+            return Err(Vec2DErr::EmptySource);
+        };
+        if height == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        }
+        if let Some(col) = cols.iter().find(|col| col.len() != height) {
+            return Err(Vec2DErr::HeightMismatch {
+                expected: height,
+                actual: col.len(),
+            });
+        }
+
+        let width = cols.len();
+        let mut cols: Vec<_> = cols.into_iter().map(Vec::into_iter).collect();
+        let mut cells = Vec::with_capacity(width * height);
+        for _ in 0..height {
+            for col in &mut cols {
+                // All columns have been checked to have `height` elements.
+                cells.extend(col.next());
+            }
+        }
+        Ok(Self { cells, width })
+    }
+
     /// Clears the grid, discarding all the values.
-    /// Sets `width` to 0.
+    /// Sets `width` to 0, leaving a 0x0 grid whose width is set by the next
+    /// appended row.
     pub fn clear(&mut self) {
         self.cells.clear();
         self.width = 0;
     }
 
+    /// Checks the grid's internal invariants.
+    ///
+    /// A grid is valid if its width is non-zero and the number of cells is a
+    /// multiple of the width. A cleared grid (zero width and no cells) is also
+    /// considered valid.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if the width is 0 but there are cells.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the number of cells is not a
+    /// multiple of the width.
+    pub fn validate(&self) -> Result<(), Vec2DErr> {
+        if self.width == 0 && !self.cells.is_empty() {
+            return Err(Vec2DErr::ZeroWidth);
+        }
+        if self.width != 0 && !self.cells.len().is_multiple_of(self.width) {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: self.cells.len(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Returns a shared slice of all cells in row-major order.
     #[inline]
     pub fn cells(&self) -> &[T] {
@@ -276,6 +773,10 @@ impl<T> Vec2D<T> {
     /// Returns the height of the grid.
     #[inline]
     pub const fn height(&self) -> usize {
+        if self.width == 0 {
+            return 0;
+        }
+
         self.cells.len() / self.width
     }
 
@@ -290,14 +791,88 @@ impl<T> Vec2D<T> {
         }
     }
 
+    /// Returns the number of cells in a `width`x`height` grid.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::TooLarge` if the number of cells overflows, or if
+    /// they would take up more than `isize::MAX` bytes.
+    pub(crate) fn checked_len(width: usize, height: usize) -> Result<usize, Vec2DErr> {
+        width
+            .checked_mul(height)
+            .filter(|len| {
+                len.checked_mul(size_of::<T>())
+                    .is_some_and(|bytes| bytes <= isize::MAX as usize)
+            })
+            .ok_or(Vec2DErr::TooLarge)
+    }
+
+    /// Builds a `Vec2DErr::OutOfBounds` for `(x, y)` in this grid.
+    pub(crate) fn out_of_bounds(&self, x: usize, y: usize) -> Vec2DErr {
+        Vec2DErr::OutOfBounds {
+            x,
+            y,
+            width: self.width,
+            height: self.height(),
+        }
+    }
+
+    /// Returns the index of `(x, y)`, or a `Vec2DErr::OutOfBounds` error.
+    pub(crate) fn try_index_of(&self, x: usize, y: usize) -> Result<usize, Vec2DErr> {
+        self.index_of(x, y).ok_or_else(|| self.out_of_bounds(x, y))
+    }
+
+    /// Checks that row `y` exists.
+    pub(crate) fn check_row(&self, y: usize) -> Result<(), Vec2DErr> {
+        if y >= self.height() {
+            return Err(Vec2DErr::RowOutOfBounds {
+                y,
+                height: self.height(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that rows can be inserted before row `y`, which may be one past
+    /// the last row.
+    pub(crate) fn check_row_insert(&self, y: usize) -> Result<(), Vec2DErr> {
+        if y > self.height() {
+            return Err(Vec2DErr::RowOutOfBounds {
+                y,
+                height: self.height(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that column `x` exists.
+    pub(crate) fn check_col(&self, x: usize) -> Result<(), Vec2DErr> {
+        if x >= self.width {
+            return Err(Vec2DErr::ColOutOfBounds {
+                x,
+                width: self.width,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Converts the given index into 2D coordinates (for this 2d vector).
     ///
-    /// Returns `None` if the coordinates are out of bounds.
+    /// Returns `None` if the coordinates are out of bounds. This is the same
+    /// as `coords_of`.
     pub fn coords(&self, idx: usize) -> Option<(usize, usize)> {
-        let (x, y) = (idx % self.width, idx / self.width);
+        self.coords_of(idx)
+    }
 
-        if y < self.height() {
-            Some((x, y))
+    /// Converts a linear index into `(x, y)` coordinates, the inverse of
+    /// `index_of`.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn coords_of(&self, idx: usize) -> Option<(usize, usize)> {
+        if idx < self.cells.len() {
+            Some(Self::create_coords(idx, self.width))
         } else {
             None
         }
@@ -335,6 +910,72 @@ impl<T> Vec2D<T> {
         }
     }
 
+    /// Returns a shared reference to the cell at `(x, y)`, if it exists.
+    ///
+    /// Negative coordinates are out of bounds, so offsets can be applied
+    /// without checking for underflow first.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        self.get(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`, if it exists.
+    ///
+    /// Negative coordinates are out of bounds, so offsets can be applied
+    /// without checking for underflow first.
+    pub fn get_signed_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.get_mut(usize::try_from(x).ok()?, usize::try_from(y).ok()?)
+    }
+
+    /// Returns mutable references to the two cells at `a` and `b` at once.
+    ///
+    /// Returns `None` if either cell is out of bounds, or if `a == b`.
+    pub fn get_disjoint_mut(
+        &mut self,
+        a: (usize, usize),
+        b: (usize, usize),
+    ) -> Option<(&mut T, &mut T)> {
+        let a = self.index_of(a.0, a.1)?;
+        let b = self.index_of(b.0, b.1)?;
+
+        let [a, b] = self.cells.get_disjoint_mut([a, b]).ok()?;
+        Some((a, b))
+    }
+
+    /// Swaps the cells at `(x1, y1)` and `(x2, y2)`.
+    ///
+    /// # Panics
+    /// Panics if either cell is out of bounds. See `try_swap` for a
+    /// non-panicking version.
+    pub fn swap(&mut self, (x1, y1): (usize, usize), (x2, y2): (usize, usize)) {
+        self.try_swap((x1, y1), (x2, y2)).unwrap_or_else(|_| {
+            panic!(
+                "Vec2D swap out of bounds: ({}, {}) and ({}, {}) in a {}x{} grid.",
+                x1,
+                y1,
+                x2,
+                y2,
+                self.width,
+                self.height()
+            )
+        });
+    }
+
+    /// Swaps the cells at `(x1, y1)` and `(x2, y2)`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if either cell is out of bounds.
+    pub fn try_swap(
+        &mut self,
+        (x1, y1): (usize, usize),
+        (x2, y2): (usize, usize),
+    ) -> Result<(), Vec2DErr> {
+        let a = self.try_index_of(x1, y1)?;
+        let b = self.try_index_of(x2, y2)?;
+
+        self.cells.swap(a, b);
+        Ok(())
+    }
+
     /// Returns a shared slice representing row `y`, if it exists.
     pub fn get_row(&self, y: usize) -> Option<&[T]> {
         if y >= self.height() {
@@ -357,24 +998,77 @@ impl<T> Vec2D<T> {
         Some(&mut self.cells[start..end])
     }
 
+    /// Overwrites row `y` with the contents of `src`, in place.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y` is out of bounds.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the length of `src` differs from
+    /// the 2D vector's width.
+    pub fn copy_row_from_slice(&mut self, y: usize, src: &[T]) -> Result<(), Vec2DErr>
+    where
+        T: Copy,
+    {
+        self.row_for_overwrite(y, src.len())?.copy_from_slice(src);
+        Ok(())
+    }
+
+    /// Overwrites row `y` with clones of the cells in `src`, in place.
+    ///
+    /// This is the equivalent of `copy_row_from_slice` for types that are
+    /// `Clone`, but not `Copy`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y` is out of bounds.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the length of `src` differs from
+    /// the 2D vector's width.
+    pub fn clone_row_from(&mut self, y: usize, src: &[T]) -> Result<(), Vec2DErr>
+    where
+        T: Clone,
+    {
+        self.row_for_overwrite(y, src.len())?.clone_from_slice(src);
+        Ok(())
+    }
+
+    /// Returns row `y`, checking that it can be overwritten by `len` cells.
+    fn row_for_overwrite(&mut self, y: usize, len: usize) -> Result<&mut [T], Vec2DErr> {
+        self.check_row(y)?;
+        if len != self.width {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: len,
+            });
+        }
+
+        let start = y * self.width;
+        Ok(&mut self.cells[start..start + self.width])
+    }
+
     /// Appends a row (or rows) at the end of the vector.
     ///
     /// The row's size has to be a multiple of the 2D vector's width.
     /// If the row's size is bigger that the 2D vector's width, but is still
     /// a multiple of width, the row will be inserted as multiple rows.
+    /// If the 2D vector is 0x0, the row sets its width.
     ///
     /// This function implies it **will empty the original container upon insertion**.
     /// If you want to keep the container's contents, consider `extend_cloned`.
     ///
     /// # Errors:
-    /// Returns `Vec2DErr::WidthMismatch(*rows_length*, *2d_vectors_width*)` if the
-    /// row's length is not a multiple of the 2D vector's width.
+    /// Returns `Vec2DErr::WidthMismatch` if the row's length is not a multiple
+    /// of the 2D vector's width.
     pub fn extend(&mut self, row: Vec<T>) -> Result<(), Vec2DErr> {
+        self.adopt_width(row.len());
         if !row.len().is_multiple_of(self.width) {
-            return Err(Vec2DErr::WidthMismatch(row.len(), self.width));
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: row.len(),
+            });
         }
 
         self.cells.extend(row);
+        self.debug_validate();
         Ok(())
     }
 
@@ -383,6 +1077,7 @@ impl<T> Vec2D<T> {
     /// The row's size has to be a multiple of the 2D vector's width.
     /// If the row's size is bigger that the 2D vector's width, but is still
     /// a multiple of width, the row will be inserted as multiple rows.
+    /// If the 2D vector is 0x0, the row sets its width.
     ///
     /// This function implies that the row's elements can be cloned.
     ///
@@ -390,17 +1085,77 @@ impl<T> Vec2D<T> {
     /// the original container, and is slower than `extend`.
     ///
     /// # Errors:
-    /// Returns `Vec2DErr::WidthMismatch(*rows_length*, *2d_vectors_width*)` if the
-    /// row's length is not a multiple of the 2D vector's width.
+    /// Returns `Vec2DErr::WidthMismatch` if the row's length is not a multiple
+    /// of the 2D vector's width.
     pub fn extend_cloned(&mut self, row: &[T]) -> Result<(), Vec2DErr>
     where
         T: Clone,
     {
+        self.adopt_width(row.len());
         if !row.len().is_multiple_of(self.width) {
-            return Err(Vec2DErr::WidthMismatch(row.len(), self.width));
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: row.len(),
+            });
         }
 
         self.cells.extend(row.iter().cloned());
+        self.debug_validate();
+        Ok(())
+    }
+
+    /// Appends all rows of `other` at the end of the vector. If the vector
+    /// is 0x0, it takes the width of `other`. If `other` has no rows, the
+    /// vector is left unchanged.
+    ///
+    /// The other grid's storage is moved over in one go, instead of being
+    /// extended row by row.
+    ///
+    /// # Errors:
+    /// Returns `Vec2DErr::WidthMismatch` if the grids have different widths.
+    pub fn append_grid(&mut self, mut other: Vec2D<T>) -> Result<(), Vec2DErr> {
+        if other.cells.is_empty() {
+            return Ok(());
+        }
+        self.adopt_width(other.width);
+        if other.width != self.width {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: other.width,
+            });
+        }
+
+        self.cells.append(&mut other.cells);
+        self.debug_validate();
+        Ok(())
+    }
+
+    /// Appends all rows of `other` at the end of the vector. If the vector
+    /// is 0x0, it takes the width of `other`. If `other` has no rows, the
+    /// vector is left unchanged.
+    ///
+    /// This should only be used if you need to keep the other grid, and is
+    /// slower than `append_grid`.
+    ///
+    /// # Errors:
+    /// Returns `Vec2DErr::WidthMismatch` if the grids have different widths.
+    pub fn append_grid_cloned(&mut self, other: &Vec2D<T>) -> Result<(), Vec2DErr>
+    where
+        T: Clone,
+    {
+        if other.cells.is_empty() {
+            return Ok(());
+        }
+        self.adopt_width(other.width);
+        if other.width != self.width {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: other.width,
+            });
+        }
+
+        self.cells.extend_from_slice(&other.cells);
+        self.debug_validate();
         Ok(())
     }
 
@@ -409,20 +1164,30 @@ impl<T> Vec2D<T> {
     /// The row's size has to be a multiple of the 2D vector's width.
     /// If the row's size is bigger that the 2D vector's width, but is still
     /// a multiple of width, the row will be inserted as multiple rows.
+    /// Inserting at `y == height` appends the row, like `extend`.
     ///
     /// Note, that the function will discard the given row, so if you want to
     /// keep it for some reason, consider cloning it before passing it in, or
     /// using `insert_row_cloned`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y > height`.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the row's length is not a multiple
+    /// of the 2D vector's width.
     pub fn insert_row(&mut self, y: usize, row: Vec<T>) -> Result<(), Vec2DErr> {
-        if y >= self.height() {
-            return Err(Vec2DErr::OutOfBounds);
-        }
+        self.check_row_insert(y)?;
+        self.adopt_width(row.len());
         if !row.len().is_multiple_of(self.width) {
-            return Err(Vec2DErr::WidthMismatch(row.len(), self.width));
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: row.len(),
+            });
         }
 
         let idx = y * self.width;
         self.cells.splice(idx..idx, row);
+        self.debug_validate();
         Ok(())
     }
 
@@ -431,47 +1196,305 @@ impl<T> Vec2D<T> {
     /// The row's size has to be a multiple of the 2D vector's width.
     /// If the row's size is bigger that the 2D vector's width, but is still
     /// a multiple of width, the row will be inserted as multiple rows.
+    /// Inserting at `y == height` appends the row, like `extend_cloned`.
     ///
     /// This function implies that the row's contents can be cloned.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y > height`.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the row's length is not a multiple
+    /// of the 2D vector's width.
     pub fn insert_row_cloned(&mut self, y: usize, row: &[T]) -> Result<(), Vec2DErr>
     where
         T: Clone,
     {
-        if y >= self.height() {
-            return Err(Vec2DErr::OutOfBounds);
-        }
+        self.check_row_insert(y)?;
+        self.adopt_width(row.len());
         if !row.len().is_multiple_of(self.width) {
-            return Err(Vec2DErr::WidthMismatch(row.len(), self.width));
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: row.len(),
+            });
         }
 
         let idx = y * self.width;
         self.cells.splice(idx..idx, row.iter().cloned());
+        self.debug_validate();
+        Ok(())
+    }
+
+    /// Inserts the cells yielded by `cells` as rows at a given y coordinate,
+    /// without collecting them into a `Vec` first.
+    ///
+    /// The number of cells has to be a multiple of the 2D vector's width. If
+    /// the vector is 0x0, the cells are inserted as a single row, which sets
+    /// its width. Inserting at `y == height` appends the rows.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y > height`.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the number of cells is not a
+    /// multiple of the 2D vector's width. In that case, the vector is left
+    /// untouched.
+    pub fn insert_rows(
+        &mut self,
+        y: usize,
+        cells: impl IntoIterator<Item = T>,
+    ) -> Result<(), Vec2DErr> {
+        self.check_row_insert(y)?;
+
+        let len = self.cells.len();
+        self.cells.extend(cells);
+        let inserted = self.cells.len() - len;
+        self.adopt_width(inserted);
+        if !inserted.is_multiple_of(self.width) {
+            self.cells.truncate(len);
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: inserted,
+            });
+        }
+
+        self.move_tail_to_row(len, y);
+        Ok(())
+    }
+
+    /// Inserts each row yielded by `rows` at a given y coordinate, without
+    /// collecting them into a `Vec` first.
+    ///
+    /// Every row has to be as long as the 2D vector's width. If the vector is
+    /// 0x0, the first row sets its width. Inserting at `y == height` appends
+    /// the rows.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y > height`.
+    ///
+    /// Returns `Vec2DErr::ZeroWidth` if the vector is 0x0 and the first row is
+    /// empty.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if any row's length differs from the
+    /// 2D vector's width. In either case, the vector is left untouched.
+    pub fn insert_rows_from_iter<R>(
+        &mut self,
+        y: usize,
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<(), Vec2DErr>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        self.check_row_insert(y)?;
+
+        let (len, width) = (self.cells.len(), self.width);
+        for row in rows {
+            let start = self.cells.len();
+            self.cells.extend(row);
+            self.adopt_width(self.cells.len() - start);
+            if self.width == 0 || self.cells.len() - start != self.width {
+                let err = match self.width {
+                    0 => Vec2DErr::ZeroWidth,
+                    _ => Vec2DErr::WidthMismatch {
+                        expected: self.width,
+                        actual: self.cells.len() - start,
+                    },
+                };
+                self.cells.truncate(len);
+                self.width = width;
+                return Err(err);
+            }
+        }
+
+        self.move_tail_to_row(len, y);
         Ok(())
     }
 
+    /// Moves the cells past index `from`, which were just appended, in front
+    /// of row `y`.
+    fn move_tail_to_row(&mut self, from: usize, y: usize) {
+        let idx = y * self.width;
+        self.cells[idx..].rotate_left(from - idx);
+        self.debug_validate();
+    }
+
     /// Discards a row at the given y coordinate, without preserving the values.
     pub fn discard_row(&mut self, y: usize) -> Result<(), Vec2DErr> {
-        if y >= self.height() {
-            return Err(Vec2DErr::OutOfBounds);
-        }
+        self.check_row(y)?;
 
         let start = y * self.width;
         let end = start + self.width;
         self.cells.drain(start..end);
+        self.debug_validate();
 
         Ok(())
     }
 
     /// Removes a row at the given y coordinate, returning the removed values.
     pub fn remove_row(&mut self, y: usize) -> Result<Vec<T>, Vec2DErr> {
-        if y >= self.height() {
-            return Err(Vec2DErr::OutOfBounds);
-        }
+        self.check_row(y)?;
 
         let start = y * self.width;
         let end = start + self.width;
 
-        Ok(self.cells.drain(start..end).collect())
+        let row = self.cells.drain(start..end).collect();
+        self.debug_validate();
+
+        Ok(row)
+    }
+
+    /// Removes a row at the given y coordinate, returning the removed values.
+    ///
+    /// The last row takes the place of the removed one, so this doesn't
+    /// shift all the following rows, but changes the order of the rows.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y` is out of bounds.
+    pub fn swap_remove_row(&mut self, y: usize) -> Result<Vec<T>, Vec2DErr> {
+        self.check_row(y)?;
+
+        let last = self.cells.len() - self.width;
+        let start = y * self.width;
+        if start != last {
+            let (head, tail) = self.cells.split_at_mut(last);
+            head[start..start + self.width].swap_with_slice(tail);
+        }
+
+        let row = self.cells.split_off(last);
+        self.debug_validate();
+
+        Ok(row)
+    }
+
+    /// Stacks `other` below this grid, consuming both. If this grid is 0x0,
+    /// it takes the width of `other`. If `other` has no rows, this grid is
+    /// returned unchanged.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::WidthMismatch` if the grids have different widths.
+    pub fn vcat(mut self, other: Vec2D<T>) -> Result<Vec2D<T>, Vec2DErr> {
+        if other.cells.is_empty() {
+            return Ok(self);
+        }
+        self.adopt_width(other.width);
+        if other.width != self.width {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: other.width,
+            });
+        }
+
+        self.cells.extend(other.cells);
+        self.debug_validate();
+        Ok(self)
+    }
+
+    /// Places `other` to the right of this grid, consuming both.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::HeightMismatch` if the grids have different heights.
+    pub fn hcat(self, other: Vec2D<T>) -> Result<Vec2D<T>, Vec2DErr> {
+        if other.height() != self.height() {
+            return Err(Vec2DErr::HeightMismatch {
+                expected: self.height(),
+                actual: other.height(),
+            });
+        }
+
+        let width = self.width + other.width;
+        let mut cells = Vec::with_capacity(self.cells.len() + other.cells.len());
+        let mut left = self.cells.into_iter();
+        let mut right = other.cells.into_iter();
+        while left.len() > 0 {
+            cells.extend(left.by_ref().take(self.width));
+            cells.extend(right.by_ref().take(other.width));
+        }
+
+        Ok(Self { cells, width })
+    }
+
+    /// Splits the grid into two owned grids, consuming it.
+    /// The first grid contains rows `0..y`, the second rows `y..height`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if either of the grids would be
+    /// empty, i.e. if `y == 0` or `y >= height`.
+    pub fn split_at_row(mut self, y: usize) -> Result<(Vec2D<T>, Vec2D<T>), Vec2DErr> {
+        if y == 0 || y >= self.height() {
+            return Err(Vec2DErr::RowOutOfBounds {
+                y,
+                height: self.height(),
+            });
+        }
+
+        let bottom = self.cells.split_off(y * self.width);
+        let width = self.width;
+        Ok((
+            self,
+            Self {
+                cells: bottom,
+                width,
+            },
+        ))
+    }
+
+    /// Moves the rows of the grid into two new grids of the same width,
+    /// consuming it. The first grid contains the rows that satisfy `pred`,
+    /// the second all others, both in their original order.
+    ///
+    /// Either side is `None` if no rows ended up in it.
+    pub fn partition_rows(
+        self,
+        mut pred: impl FnMut(&[T]) -> bool,
+    ) -> (Option<Vec2D<T>>, Option<Vec2D<T>>) {
+        let matches: Vec<bool> = self.iter_rows().map(&mut pred).collect();
+        let matching = matches.iter().filter(|&&m| m).count();
+
+        let width = self.width;
+        let mut left = Vec::with_capacity(matching * width);
+        let mut right = Vec::with_capacity(self.cells.len() - matching * width);
+        let mut cells = self.cells.into_iter();
+        for m in matches {
+            let side = if m { &mut left } else { &mut right };
+            side.extend(cells.by_ref().take(width));
+        }
+
+        let grid = |cells: Vec<T>| (!cells.is_empty()).then_some(Vec2D { cells, width });
+        (grid(left), grid(right))
+    }
+
+    /// Splits the grid into two owned grids, consuming it.
+    /// The first grid contains columns `0..x`, the second columns `x..width`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ColOutOfBounds` if either of the grids would be
+    /// empty, i.e. if `x == 0` or `x >= width`.
+    pub fn split_at_col(self, x: usize) -> Result<(Vec2D<T>, Vec2D<T>), Vec2DErr> {
+        if x == 0 || x >= self.width {
+            return Err(Vec2DErr::ColOutOfBounds {
+                x,
+                width: self.width,
+            });
+        }
+
+        let height = self.height();
+        let right_width = self.width - x;
+        let mut left = Vec::with_capacity(x * height);
+        let mut right = Vec::with_capacity(right_width * height);
+        let mut cells = self.cells.into_iter();
+        while cells.len() > 0 {
+            left.extend(cells.by_ref().take(x));
+            right.extend(cells.by_ref().take(right_width));
+        }
+
+        Ok((
+            Self {
+                cells: left,
+                width: x,
+            },
+            Self {
+                cells: right,
+                width: right_width,
+            },
+        ))
     }
 
     /// Iterates over all cells, yielding their `(x, y)` coordinates and values.
@@ -492,14 +1515,164 @@ impl<T> Vec2D<T> {
             .map(move |(idx, cell)| ((idx % width, idx / width), cell))
     }
 
+    /// Iterates over all cells, yielding their linear index, `(x, y)`
+    /// coordinates and values.
+    ///
+    /// The index is the cell's position in `cells()`, so it can be used for
+    /// auxiliary flat arrays of the same size as the grid.
+    pub fn enumerate_cells(&self) -> impl Iterator<Item = (usize, (usize, usize), &T)> {
+        self.iter_rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+            .enumerate()
+            .map(|(idx, (pos, cell))| (idx, pos, cell))
+    }
+
+    /// Iterates mutably over all cells, yielding their linear index, `(x, y)`
+    /// coordinates and values.
+    pub fn enumerate_cells_mut(&mut self) -> impl Iterator<Item = (usize, (usize, usize), &mut T)> {
+        self.iter_rows_mut()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter_mut()
+                    .enumerate()
+                    .map(move |(x, cell)| ((x, y), cell))
+            })
+            .enumerate()
+            .map(|(idx, (pos, cell))| (idx, pos, cell))
+    }
+
+    /// Iterates over all cells that differ between this grid and `other`,
+    /// yielding their `(x, y)` coordinates, this grid's value, and the other
+    /// grid's value.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::WidthMismatch` if the grids have different widths.
+    ///
+    /// Returns `Vec2DErr::HeightMismatch` if the grids have different heights.
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a Vec2D<T>,
+    ) -> Result<impl Iterator<Item = ((usize, usize), &'a T, &'a T)>, Vec2DErr>
+    where
+        T: PartialEq,
+    {
+        if other.width != self.width {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: other.width,
+            });
+        } else if other.height() != self.height() {
+            return Err(Vec2DErr::HeightMismatch {
+                expected: self.height(),
+                actual: other.height(),
+            });
+        }
+
+        Ok(self
+            .iter_xy()
+            .zip(&other.cells)
+            .filter(|((_, a), b)| a != b)
+            .map(|((pos, a), b)| (pos, a, b)))
+    }
+
     /// Iterates over grid rows as shared slices.
     pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
-        self.cells.chunks_exact(self.width)
+        self.cells.chunks_exact(self.chunk_width())
     }
 
     /// Iterates over grid rows as mutable slices
     pub fn iter_rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
-        self.cells.chunks_exact_mut(self.width)
+        let width = self.chunk_width();
+        self.cells.chunks_exact_mut(width)
+    }
+
+    /// Returns a shared slice containing the rows in `range`, if they exist.
+    pub fn rows(&self, range: core::ops::Range<usize>) -> Option<&[T]> {
+        if range.start > range.end || range.end > self.height() {
+            return None;
+        }
+
+        Some(&self.cells[range.start * self.width..range.end * self.width])
+    }
+
+    /// Iterates over the rows in `range` as shared slices, if they exist.
+    pub fn iter_rows_range(
+        &self,
+        range: core::ops::Range<usize>,
+    ) -> Option<impl Iterator<Item = &[T]>> {
+        Some(self.rows(range)?.chunks_exact(self.chunk_width()))
+    }
+
+    /// Consumes the grid and returns its rows as separate vectors.
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let mut cells = self.cells;
+        let mut rows = Vec::with_capacity(cells.len().checked_div(self.width).unwrap_or(0));
+
+        // Splitting off the tail moves a whole row at a time, without
+        // shifting the remaining cells.
+        while !cells.is_empty() {
+            rows.push(cells.split_off(cells.len() - self.width));
+        }
+        rows.reverse();
+        rows
+    }
+
+    /// Consumes the grid and lazily yields its rows as separate vectors, top
+    /// to bottom, without cloning any cells.
+    pub fn into_iter_rows(self) -> impl ExactSizeIterator<Item = Vec<T>> {
+        let (width, height) = (self.width, self.height());
+        // Draining the front of a deque moves a whole row at a time, without
+        // shifting the remaining cells.
+        let mut cells = VecDeque::from(self.cells);
+
+        (0..height).map(move |_| cells.drain(..width).collect())
+    }
+
+    /// Returns a helper that prints the grid with the given cell and row
+    /// separators.
+    pub fn display_with<'a>(
+        &'a self,
+        separator: &'a str,
+        row_separator: &'a str,
+    ) -> DisplayWith<'a, T> {
+        DisplayWith::new(self, separator, row_separator)
+    }
+
+    /// Renders the grid as text, converting each cell into a character with `f`.
+    ///
+    /// Every row is written on its own line, terminated by `'\n'`.
+    pub fn render_ascii(&self, f: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity(self.cells.len() + self.height());
+        for row in self.iter_rows() {
+            out.extend(row.iter().map(&f));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Returns a copy of the grid's rows as separate vectors.
+    pub fn to_rows(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.iter_rows().map(<[T]>::to_vec).collect()
+    }
+
+    /// Sets every cell to a clone of `value`.
+    ///
+    /// For `Copy` primitives, this lowers to a `memset` or a vectorized loop.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+
+    /// Sets every cell to the value returned by calling `f`, in row-major
+    /// order.
+    pub fn fill_with(&mut self, f: impl FnMut() -> T) {
+        self.cells.fill_with(f);
     }
 
     /// Applies a function `f` to each cell without cloning the grid.
@@ -515,7 +1688,10 @@ impl<T> Vec2D<T> {
     /// Given the wanted height of our vector, cuts off all the exess rows.
     pub fn truncate_rows(&mut self, new_height: usize) -> Result<(), Vec2DErr> {
         if new_height > self.height() {
-            return Err(Vec2DErr::OutOfBounds);
+            return Err(Vec2DErr::RowOutOfBounds {
+                y: new_height,
+                height: self.height(),
+            });
         } else if new_height == self.height() {
             return Ok(());
         } else if new_height == 0 {
@@ -524,6 +1700,7 @@ impl<T> Vec2D<T> {
 
         let start = new_height * self.width;
         self.cells.drain(start..);
+        self.debug_validate();
 
         Ok(())
     }
@@ -531,7 +1708,10 @@ impl<T> Vec2D<T> {
     /// Given the wanted width of our vector, cuts off all the exess columns.
     pub fn truncate_cols(&mut self, new_width: usize) -> Result<(), Vec2DErr> {
         if new_width > self.width {
-            return Err(Vec2DErr::WidthMismatch(new_width, self.width));
+            return Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: new_width,
+            });
         } else if new_width == self.width {
             return Ok(());
         } else if new_width == 0 {
@@ -547,9 +1727,38 @@ impl<T> Vec2D<T> {
         });
 
         self.width = new_width;
+        self.debug_validate();
         Ok(())
     }
 
+    /// Returns the width to split the cells into rows by.
+    ///
+    /// A 0x0 grid has a width of 0, but it has no cells to split either.
+    #[inline]
+    pub(crate) fn chunk_width(&self) -> usize {
+        self.width.max(1)
+    }
+
+    /// Sets the width of a 0x0 grid, which is decided by the first rows
+    /// appended to it.
+    #[inline]
+    fn adopt_width(&mut self, width: usize) {
+        if self.width == 0 {
+            self.width = width;
+        }
+    }
+
+    /// Asserts the grid's invariants in debug builds, after structural changes.
+    #[inline]
+    pub(crate) fn debug_validate(&self) {
+        debug_assert!(
+            self.validate().is_ok(),
+            "Vec2D invariant violated: {} cells in a grid of width {}.",
+            self.cells.len(),
+            self.width
+        );
+    }
+
     #[inline]
     fn in_bounds(&self, x: i128, y: i128) -> bool {
         x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height()
@@ -629,17 +1838,45 @@ impl<T> Vec2D<T> {
     }
 }
 
-impl std::fmt::Display for Vec2DErr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Vec2DErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
+            Vec2DErr::ByteLengthMismatch { len, cell_size } => write!(
+                f,
+                "Byte length {} is not a multiple of the cell size {}.",
+                len, cell_size
+            ),
             Vec2DErr::EmptySource => write!(f, "Source vector is empty."),
-            Vec2DErr::OutOfBounds => {
-                write!(f, "Attempted to access an index which is out of bounds.")
-            }
-            Vec2DErr::WidthMismatch(width1, width2) => write!(
+            Vec2DErr::HeightMismatch { expected, actual } => write!(
+                f,
+                "Height {} is not compatible with the expected height {}.",
+                actual, expected
+            ),
+            Vec2DErr::OutOfBounds {
+                x,
+                y,
+                width,
+                height,
+            } => write!(
+                f,
+                "Coordinates (x: {}, y: {}) are out of bounds of a {}x{} grid.",
+                x, y, width, height
+            ),
+            Vec2DErr::RowOutOfBounds { y, height } => write!(
+                f,
+                "Row {} is out of bounds of a grid of height {}.",
+                y, height
+            ),
+            Vec2DErr::ColOutOfBounds { x, width } => write!(
+                f,
+                "Column {} is out of bounds of a grid of width {}.",
+                x, width
+            ),
+            Vec2DErr::TooLarge => write!(f, "Grid dimensions are too large."),
+            Vec2DErr::WidthMismatch { expected, actual } => write!(
                 f,
-                "Widths {} and {} are not compatible for the given task.",
-                width1, width2
+                "Width {} is not compatible with the expected width {}.",
+                actual, expected
             ),
             Vec2DErr::ZeroWidth => write!(f, "Width must be bigger than 0."),
             Vec2DErr::ZeroHeight => write!(f, "Height must be bigger than 0."),
@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// A cell selected by a mask, along with its `(x, y)` coordinates.
+type MaskedCell<'a, T> = ((usize, usize), &'a T);
+
+impl<T> Vec2D<T> {
+    /// Returns a mask of the same dimensions, which is `true` for every cell
+    /// that satisfies `pred`.
+    pub fn threshold_by(&self, pred: impl FnMut(&T) -> bool) -> Vec2D<bool> {
+        Vec2D {
+            cells: self.cells.iter().map(pred).collect(),
+            width: self.width,
+        }
+    }
+
+    /// Returns a mask of the same dimensions, which is `true` for every cell
+    /// that is greater than or equal to `cutoff`.
+    pub fn threshold(&self, cutoff: &T) -> Vec2D<bool>
+    where
+        T: PartialOrd,
+    {
+        self.threshold_by(|cell| cell >= cutoff)
+    }
+
+    /// Calls `f` on every cell whose counterpart in `mask` is `true`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::WidthMismatch` if the widths of the grid and the mask
+    /// differ.
+    ///
+    /// Returns `Vec2DErr::HeightMismatch` if the heights of the grid and the
+    /// mask differ.
+    pub fn apply_where(
+        &mut self,
+        mask: &Vec2D<bool>,
+        mut f: impl FnMut(&mut T),
+    ) -> Result<(), Vec2DErr> {
+        self.check_shape(mask)?;
+
+        self.cells
+            .iter_mut()
+            .zip(&mask.cells)
+            .filter(|(_, selected)| **selected)
+            .for_each(|(cell, _)| f(cell));
+        Ok(())
+    }
+
+    /// Returns the coordinates and values of every cell whose counterpart in
+    /// `mask` is `true`, in row-major order.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::WidthMismatch` if the widths of the grid and the mask
+    /// differ.
+    ///
+    /// Returns `Vec2DErr::HeightMismatch` if the heights of the grid and the
+    /// mask differ.
+    pub fn extract_masked(&self, mask: &Vec2D<bool>) -> Result<Vec<MaskedCell<'_, T>>, Vec2DErr> {
+        self.check_shape(mask)?;
+
+        Ok(self
+            .iter_xy()
+            .zip(&mask.cells)
+            .filter(|(_, selected)| **selected)
+            .map(|(cell, _)| cell)
+            .collect())
+    }
+}
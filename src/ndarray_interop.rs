@@ -0,0 +1,51 @@
+use ndarray::Array2;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// Converts the grid into an `(height, width)` shaped array, reusing the
+/// grid's storage.
+impl<T> From<Vec2D<T>> for Array2<T> {
+    fn from(value: Vec2D<T>) -> Self {
+        let shape = (value.height(), value.width);
+
+        // The storage is row-major and holds exactly `width * height` cells.
+        Array2::from_shape_vec(shape, value.cells).expect("shape matches storage")
+    }
+}
+
+/// Converts an `(height, width)` shaped array into a grid.
+///
+/// Arrays in standard (row-major) layout reuse their storage, while any
+/// other layout is copied over in logical order.
+///
+/// # Errors
+/// Returns `Vec2DErr::ZeroWidth` if the array has no columns.
+/// Returns `Vec2DErr::ZeroHeight` if the array has no rows.
+impl<T> TryFrom<Array2<T>> for Vec2D<T> {
+    type Error = Vec2DErr;
+
+    fn try_from(value: Array2<T>) -> Result<Self, Self::Error> {
+        let (height, width) = value.dim();
+        if width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        } else if height == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        }
+
+        if value.is_standard_layout() {
+            // A standard layout array is contiguous, but may only cover a part
+            // of its storage if it was sliced.
+            let (mut cells, offset) = value.into_raw_vec_and_offset();
+            let offset = offset.unwrap_or(0);
+            cells.truncate(offset + width * height);
+            cells.drain(..offset);
+
+            return Ok(Self { cells, width });
+        }
+
+        Ok(Self {
+            cells: value.into_iter().collect(),
+            width,
+        })
+    }
+}
@@ -0,0 +1,80 @@
+use core::fmt::Write as _;
+use std::io;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::Vec2D;
+
+/// The maximum length of a line in a plain PGM or PPM image.
+const MAX_LINE: usize = 70;
+
+/// Writes the samples of a plain image, starting every row on a new line and
+/// wrapping lines before they exceed `MAX_LINE` characters.
+fn write_plain_rows<W, R>(mut writer: W, rows: impl Iterator<Item = R>) -> io::Result<()>
+where
+    W: io::Write,
+    R: IntoIterator<Item = u8>,
+{
+    let mut line = String::with_capacity(MAX_LINE);
+    for row in rows {
+        for sample in row {
+            // A sample takes up at most 3 characters, plus a separator.
+            if line.len() + 4 > MAX_LINE {
+                writeln!(writer, "{}", line)?;
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            write!(line, "{}", sample).expect("writing to a String can't fail");
+        }
+
+        writeln!(writer, "{}", line)?;
+        line.clear();
+    }
+    Ok(())
+}
+
+impl Vec2D<u8> {
+    /// Writes the grid as a binary (P5) PGM grayscale image, with one pixel
+    /// per cell.
+    pub fn write_pgm<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "P5\n{} {}\n255\n", self.width, self.height())?;
+        writer.write_all(&self.cells)
+    }
+
+    /// Writes the grid as a plain (P2) PGM grayscale image, with one pixel
+    /// per cell.
+    ///
+    /// Every row starts on a new line, and lines are wrapped so that none
+    /// exceeds 70 characters.
+    pub fn write_pgm_ascii<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "P2\n{} {}\n255\n", self.width, self.height())?;
+        write_plain_rows(writer, self.iter_rows().map(|row| row.iter().copied()))
+    }
+}
+
+impl Vec2D<(u8, u8, u8)> {
+    /// Writes the grid as a binary (P6) PPM color image, with one `(r, g, b)`
+    /// pixel per cell.
+    pub fn write_ppm<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height())?;
+        let bytes: Vec<u8> = self.cells.iter().flat_map(|&(r, g, b)| [r, g, b]).collect();
+        writer.write_all(&bytes)
+    }
+
+    /// Writes the grid as a plain (P3) PPM color image, with one `(r, g, b)`
+    /// pixel per cell.
+    ///
+    /// Every row starts on a new line, and lines are wrapped so that none
+    /// exceeds 70 characters.
+    pub fn write_ppm_ascii<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "P3\n{} {}\n255\n", self.width, self.height())?;
+        write_plain_rows(
+            writer,
+            self.iter_rows()
+                .map(|row| row.iter().flat_map(|&(r, g, b)| [r, g, b])),
+        )
+    }
+}
@@ -0,0 +1,144 @@
+use core::ops::{Add, Mul, Sub};
+
+use alloc::vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+impl<T> Vec2D<T> {
+    /// Checks that `other` has the same dimensions as this grid.
+    pub(crate) fn check_shape<U>(&self, other: &Vec2D<U>) -> Result<(), Vec2DErr> {
+        if other.width != self.width {
+            Err(Vec2DErr::WidthMismatch {
+                expected: self.width,
+                actual: other.width,
+            })
+        } else if other.height() != self.height() {
+            Err(Vec2DErr::HeightMismatch {
+                expected: self.height(),
+                actual: other.height(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Combines the cells of both grids pairwise with `f`.
+    fn zip_with<U, R>(
+        self,
+        other: Vec2D<U>,
+        f: impl FnMut((T, U)) -> R,
+    ) -> Result<Vec2D<R>, Vec2DErr> {
+        self.check_shape(&other)?;
+
+        Ok(Vec2D {
+            cells: self.cells.into_iter().zip(other.cells).map(f).collect(),
+            width: self.width,
+        })
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T> + Mul<Output = T>> Vec2D<T> {
+    /// Returns the matrix product of this grid and `other`, where rows are
+    /// the grid's rows.
+    ///
+    /// The result has the height of this grid and the width of `other`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::HeightMismatch` if the height of `other` differs from
+    /// the width of this grid.
+    ///
+    /// Returns `Vec2DErr::TooLarge` if the result would be too large to
+    /// allocate.
+    pub fn matmul(&self, other: &Vec2D<T>) -> Result<Vec2D<T>, Vec2DErr> {
+        if other.height() != self.width {
+            return Err(Vec2DErr::HeightMismatch {
+                expected: self.width,
+                actual: other.height(),
+            });
+        }
+
+        let mut cells = vec![T::default(); Self::checked_len(other.width, self.height())?];
+
+        // The i-k-j loop order walks both `other` and the output row by row,
+        // instead of striding down the columns of `other`.
+        for (out_row, row) in cells
+            .chunks_exact_mut(other.chunk_width())
+            .zip(self.iter_rows())
+        {
+            for (&a, other_row) in row.iter().zip(other.iter_rows()) {
+                for (out, &b) in out_row.iter_mut().zip(other_row) {
+                    *out = *out + a * b;
+                }
+            }
+        }
+
+        Ok(Vec2D {
+            cells,
+            width: other.width,
+        })
+    }
+}
+
+macro_rules! elementwise_op {
+    ($trait:ident, $method:ident, $try_method:ident, $name:literal) => {
+        impl<T: Clone + $trait<Output = T>> Vec2D<T> {
+            #[doc = concat!("Returns the element-wise ", $name, " of both grids.")]
+            ///
+            /// # Errors
+            /// Returns `Vec2DErr::WidthMismatch` if the widths of the grids
+            /// differ.
+            ///
+            /// Returns `Vec2DErr::HeightMismatch` if the heights of the grids
+            /// differ.
+            pub fn $try_method(&self, other: &Vec2D<T>) -> Result<Vec2D<T>, Vec2DErr> {
+                self.check_shape(other)?;
+
+                Ok(Vec2D {
+                    cells: self
+                        .cells
+                        .iter()
+                        .zip(&other.cells)
+                        .map(|(a, b)| a.clone().$method(b.clone()))
+                        .collect(),
+                    width: self.width,
+                })
+            }
+        }
+
+        #[doc = concat!("Returns the element-wise ", $name, " of both grids.")]
+        ///
+        /// # Panics
+        /// Panics if the dimensions of the grids differ.
+        impl<T: $trait<Output = T>> $trait for Vec2D<T> {
+            type Output = Vec2D<T>;
+
+            fn $method(self, other: Vec2D<T>) -> Self::Output {
+                self.zip_with(other, |(a, b)| a.$method(b)).expect(concat!(
+                    "Vec2D ",
+                    $name,
+                    " of grids with different dimensions."
+                ))
+            }
+        }
+
+        #[doc = concat!("Returns the element-wise ", $name, " of both grids.")]
+        ///
+        /// # Panics
+        /// Panics if the dimensions of the grids differ.
+        impl<T: Clone + $trait<Output = T>> $trait for &Vec2D<T> {
+            type Output = Vec2D<T>;
+
+            fn $method(self, other: &Vec2D<T>) -> Self::Output {
+                self.$try_method(other).expect(concat!(
+                    "Vec2D ",
+                    $name,
+                    " of grids with different dimensions."
+                ))
+            }
+        }
+    };
+}
+
+elementwise_op!(Add, add, try_add, "sum");
+elementwise_op!(Sub, sub, try_sub, "difference");
+elementwise_op!(Mul, mul, try_mul, "product");
@@ -0,0 +1,32 @@
+use crate::Vec2D;
+
+impl<T: Send> Vec2D<T> {
+    /// Calls `f` with the index and contents of every row, spreading the rows
+    /// over up to `threads` scoped threads.
+    ///
+    /// The rows are split into contiguous bands, one per thread, so `f` should
+    /// take roughly the same time for every row.
+    ///
+    /// # Panics
+    /// Panics if `threads == 0`, or if `f` panics.
+    pub fn for_each_row_parallel<F>(&mut self, threads: usize, f: F)
+    where
+        F: Fn(usize, &mut [T]) + Sync,
+    {
+        assert!(threads > 0, "Vec2D thread count must be non-zero.");
+
+        let width = self.chunk_width();
+        let rows_per_band = self.height().div_ceil(threads).max(1);
+        let f = &f;
+
+        std::thread::scope(|scope| {
+            for (band, cells) in self.cells.chunks_mut(rows_per_band * width).enumerate() {
+                scope.spawn(move || {
+                    for (y, row) in cells.chunks_exact_mut(width).enumerate() {
+                        f(band * rows_per_band + y, row);
+                    }
+                });
+            }
+        });
+    }
+}
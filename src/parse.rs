@@ -0,0 +1,146 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::str::FromStr;
+
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// An error produced while parsing a grid from text.
+///
+/// Line and column numbers are 1-based, as they refer to positions in the
+/// source text rather than grid coordinates.
+#[derive(Debug)]
+pub enum ParseError<E> {
+    EmptySource,
+    InvalidCell {
+        line: usize,
+        column: usize,
+        error: E,
+    },
+    WidthMismatch {
+        line: usize,
+        length: usize,
+        expected: usize,
+    },
+    ZeroWidth,
+}
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ParseError<E> {}
+
+impl<T> Vec2D<T> {
+    /// Constructs a grid from a newline-separated block of text, converting
+    /// each character into a cell with `cell`.
+    /// Every line becomes a row, so all lines must have the same length.
+    ///
+    /// # Errors
+    /// Returns `ParseError::EmptySource` if the text has no lines.
+    ///
+    /// Returns `ParseError::ZeroWidth` if the first line is empty.
+    ///
+    /// Returns `ParseError::WidthMismatch` if any line's length differs from
+    /// the length of the first line.
+    ///
+    /// Returns `ParseError::InvalidCell` with the position of the first
+    /// character `cell` fails to convert.
+    pub fn parse_str<E>(
+        input: &str,
+        cell: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError<E>> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+
+        for (line_idx, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (column_idx, c) in line.chars().enumerate() {
+                let value = cell(c).map_err(|error| ParseError::InvalidCell {
+                    line: line_idx + 1,
+                    column: column_idx + 1,
+                    error,
+                })?;
+                cells.push(value);
+            }
+
+            let length = cells.len() - start;
+            if line_idx == 0 {
+                if length == 0 {
+                    return Err(ParseError::ZeroWidth);
+                }
+                width = length;
+            } else if length != width {
+                return Err(ParseError::WidthMismatch {
+                    line: line_idx + 1,
+                    length,
+                    expected: width,
+                });
+            }
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::EmptySource);
+        }
+        Ok(Self { cells, width })
+    }
+}
+
+impl Vec2D<char> {
+    /// Constructs a grid of characters from a newline-separated block of text.
+    /// Every line becomes a row, so all lines must have the same length.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::EmptySource` if the text has no lines.
+    ///
+    /// Returns `Vec2DErr::ZeroWidth` if the first line is empty.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if any line's length differs from the
+    /// length of the first line.
+    pub fn from_str_grid(s: &str) -> Result<Self, Vec2DErr> {
+        Self::parse_str(s, Ok::<char, Infallible>).map_err(|err| match err {
+            ParseError::EmptySource => Vec2DErr::EmptySource,
+            ParseError::InvalidCell { error, .. } => match error {},
+            ParseError::WidthMismatch {
+                length, expected, ..
+            } => Vec2DErr::WidthMismatch {
+                expected,
+                actual: length,
+            },
+            ParseError::ZeroWidth => Vec2DErr::ZeroWidth,
+        })
+    }
+}
+
+/// Parses a newline-separated block of text, see [`Vec2D::from_str_grid`].
+impl FromStr for Vec2D<char> {
+    type Err = Vec2DErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_grid(s)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::EmptySource => write!(f, "Source text is empty."),
+            ParseError::InvalidCell {
+                line,
+                column,
+                error,
+            } => write!(
+                f,
+                "Invalid cell at line {}, column {}: {}",
+                line, column, error
+            ),
+            ParseError::WidthMismatch {
+                line,
+                length,
+                expected,
+            } => write!(
+                f,
+                "Line {} has length {}, but the grid's width is {}.",
+                line, length, expected
+            ),
+            ParseError::ZeroWidth => write!(f, "Width must be bigger than 0."),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// A recorded list of cell changes, which can be applied to a grid.
+///
+/// Patches can be shipped over the network as state deltas, or stored as
+/// undo steps.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridPatch<T> {
+    changes: Vec<((usize, usize), T)>,
+}
+
+impl<T> Default for GridPatch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects `((x, y), value)` pairs into a patch.
+impl<T> FromIterator<((usize, usize), T)> for GridPatch<T> {
+    fn from_iter<I: IntoIterator<Item = ((usize, usize), T)>>(iter: I) -> Self {
+        Self {
+            changes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> GridPatch<T> {
+    /// Constructs an empty patch.
+    pub fn new() -> Self {
+        Self {
+            changes: Vec::new(),
+        }
+    }
+
+    /// Constructs a patch that turns `old` into `new`, recording the values
+    /// of all cells that differ between them.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Vec2D::diff`].
+    pub fn from_diff(old: &Vec2D<T>, new: &Vec2D<T>) -> Result<Self, Vec2DErr>
+    where
+        T: Clone + PartialEq,
+    {
+        Ok(old
+            .diff(new)?
+            .map(|(pos, _, value)| (pos, value.clone()))
+            .collect())
+    }
+
+    /// Records that the cell at `(x, y)` should be set to `value`.
+    pub fn push(&mut self, x: usize, y: usize, value: T) {
+        self.changes.push(((x, y), value));
+    }
+
+    /// Returns all recorded changes, in the order they will be applied.
+    #[inline]
+    pub fn changes(&self) -> &[((usize, usize), T)] {
+        &self.changes
+    }
+
+    /// Returns the number of recorded changes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the patch doesn't record any changes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Writes all recorded changes into `grid`, in order.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if any of the changes is out of
+    /// bounds. In that case, the grid is left untouched.
+    pub fn apply(&self, grid: &mut Vec2D<T>) -> Result<(), Vec2DErr>
+    where
+        T: Clone,
+    {
+        for &((x, y), _) in &self.changes {
+            grid.try_index_of(x, y)?;
+        }
+
+        for ((x, y), value) in &self.changes {
+            grid[(*x, *y)] = value.clone();
+        }
+        Ok(())
+    }
+
+    /// Writes all recorded changes into `grid`, in order, and returns the
+    /// patch that reverts them.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if any of the changes is out of
+    /// bounds. In that case, the grid is left untouched.
+    pub fn apply_reversible(&self, grid: &mut Vec2D<T>) -> Result<GridPatch<T>, Vec2DErr>
+    where
+        T: Clone,
+    {
+        for &((x, y), _) in &self.changes {
+            grid.try_index_of(x, y)?;
+        }
+
+        let mut reverse: Vec<_> = self
+            .changes
+            .iter()
+            .map(|((x, y), value)| {
+                let old = core::mem::replace(&mut grid[(*x, *y)], value.clone());
+                ((*x, *y), old)
+            })
+            .collect();
+
+        // Undoing has to happen in the opposite order, in case a cell was
+        // changed more than once.
+        reverse.reverse();
+        Ok(Self { changes: reverse })
+    }
+}
@@ -0,0 +1,159 @@
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::Vec2D;
+
+impl<T> Vec2D<T> {
+    /// Returns the coordinates of the first cell (in row-major order) that
+    /// satisfies `pred`, if any.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let idx = self.cells.iter().position(pred)?;
+
+        Some(Self::create_coords(idx, self.width))
+    }
+
+    /// Returns the coordinates of the first cell (in row-major order) equal
+    /// to `value`, if any.
+    pub fn position_of(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns `true` if any cell is equal to `value`.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.cells.contains(value)
+    }
+
+    /// Iterates over all cells that satisfy `pred`, in row-major order,
+    /// yielding their `(x, y)` coordinates and values.
+    pub fn find_all(
+        &self,
+        mut pred: impl FnMut(&T) -> bool,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.iter_xy().filter(move |(_, cell)| pred(cell))
+    }
+
+    /// Returns the number of cells that satisfy `pred`.
+    pub fn count_where(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.cells.iter().filter(|cell| pred(cell)).count()
+    }
+
+    /// Returns the number of cells equal to `value`.
+    pub fn count_value(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        // Summing the comparisons instead of branching on them lets the loop
+        // auto-vectorize for primitives.
+        self.cells
+            .iter()
+            .map(|cell| usize::from(cell == value))
+            .sum()
+    }
+
+    /// Returns the coordinates and value of the maximum cell with respect to
+    /// `cmp`. If several cells are equally maximum, the last one (in
+    /// row-major order) is returned.
+    ///
+    /// Returns `None` if the grid is empty.
+    pub fn max_by(&self, mut cmp: impl FnMut(&T, &T) -> Ordering) -> Option<((usize, usize), &T)> {
+        self.iter_xy().max_by(|(_, a), (_, b)| cmp(a, b))
+    }
+
+    /// Returns the coordinates and value of the minimum cell with respect to
+    /// `cmp`. If several cells are equally minimum, the first one (in
+    /// row-major order) is returned.
+    ///
+    /// Returns `None` if the grid is empty.
+    pub fn min_by(&self, mut cmp: impl FnMut(&T, &T) -> Ordering) -> Option<((usize, usize), &T)> {
+        self.iter_xy().min_by(|(_, a), (_, b)| cmp(a, b))
+    }
+
+    /// Returns the coordinates and value of the maximum cell. If several
+    /// cells are equally maximum, the last one (in row-major order) is
+    /// returned.
+    ///
+    /// Returns `None` if the grid is empty.
+    pub fn argmax(&self) -> Option<((usize, usize), &T)>
+    where
+        T: Ord,
+    {
+        self.max_by(T::cmp)
+    }
+
+    /// Returns the coordinates and value of the minimum cell. If several
+    /// cells are equally minimum, the first one (in row-major order) is
+    /// returned.
+    ///
+    /// Returns `None` if the grid is empty.
+    pub fn argmin(&self) -> Option<((usize, usize), &T)>
+    where
+        T: Ord,
+    {
+        self.min_by(T::cmp)
+    }
+
+    /// Counts how many times each value occurs in the grid.
+    #[cfg(feature = "std")]
+    pub fn histogram(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone,
+    {
+        self.count_by(T::clone)
+    }
+
+    /// Counts the cells by the key returned from `key_fn`.
+    #[cfg(feature = "std")]
+    pub fn count_by<K: Eq + Hash>(&self, mut key_fn: impl FnMut(&T) -> K) -> HashMap<K, usize> {
+        let mut counts = HashMap::new();
+        for cell in &self.cells {
+            *counts.entry(key_fn(cell)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns `true` if every cell of row `y` satisfies `pred`.
+    ///
+    /// Returns `None` if `y` is out of bounds.
+    pub fn row_all(&self, y: usize, pred: impl FnMut(&T) -> bool) -> Option<bool> {
+        Some(self.get_row(y)?.iter().all(pred))
+    }
+
+    /// Returns `true` if any cell of row `y` satisfies `pred`.
+    ///
+    /// Returns `None` if `y` is out of bounds.
+    pub fn row_any(&self, y: usize, pred: impl FnMut(&T) -> bool) -> Option<bool> {
+        Some(self.get_row(y)?.iter().any(pred))
+    }
+
+    /// Returns `true` if every cell of column `x` satisfies `pred`.
+    ///
+    /// Returns `None` if `x` is out of bounds.
+    pub fn col_all(&self, x: usize, pred: impl FnMut(&T) -> bool) -> Option<bool> {
+        Some(self.col_iter(x)?.all(pred))
+    }
+
+    /// Returns `true` if any cell of column `x` satisfies `pred`.
+    ///
+    /// Returns `None` if `x` is out of bounds.
+    pub fn col_any(&self, x: usize, pred: impl FnMut(&T) -> bool) -> Option<bool> {
+        Some(self.col_iter(x)?.any(pred))
+    }
+
+    /// Iterates over the cells of column `x`, if it exists.
+    fn col_iter(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x >= self.width {
+            return None;
+        }
+
+        Some(self.cells.iter().skip(x).step_by(self.width))
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Vec2D, Vec2DErr};
+
+/// A rectangle of cells, given by its top-left corner and its dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    /// Constructs the `width`x`height` rectangle whose top-left corner is at
+    /// `(x, y)`.
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns `true` if the rectangle contains no cells.
+    pub const fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns `true` if `(x, y)` lies within the rectangle.
+    pub const fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && y >= self.y && x - self.x < self.width && y - self.y < self.height
+    }
+
+    /// Returns the overlap of both rectangles, or `None` if they don't
+    /// overlap.
+    pub fn intersect(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self
+            .x
+            .saturating_add(self.width)
+            .min(other.x.saturating_add(other.width));
+        let bottom = self
+            .y
+            .saturating_add(self.height)
+            .min(other.y.saturating_add(other.height));
+
+        let rect = Rect::new(x, y, right.saturating_sub(x), bottom.saturating_sub(y));
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// Iterates over the coordinates of all cells in the rectangle, in
+    /// row-major order.
+    ///
+    /// Like in [`Rect::intersect`], the rectangle's edges saturate at
+    /// `usize::MAX`, so coordinates beyond it are skipped.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> + use<> {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = *self;
+
+        (y..y.saturating_add(height))
+            .flat_map(move |y| (x..x.saturating_add(width)).map(move |x| (x, y)))
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Returns the rectangle covering the whole grid.
+    pub fn bounds(&self) -> Rect {
+        Rect::new(0, 0, self.width, self.height())
+    }
+
+    /// Sets every cell in `rect` to a clone of `value`.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Vec2D::view`].
+    pub fn fill_rect(&mut self, rect: Rect, value: T) -> Result<(), Vec2DErr>
+    where
+        T: Clone,
+    {
+        for (_, cell) in self.iter_rect_mut(rect)? {
+            cell.clone_from(&value);
+        }
+
+        Ok(())
+    }
+
+    /// Returns a new grid with a copy of the cells in `rect`.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Vec2D::view`].
+    pub fn crop(&self, rect: Rect) -> Result<Vec2D<T>, Vec2DErr>
+    where
+        T: Clone,
+    {
+        Ok(self.view(rect)?.to_vec2d())
+    }
+}
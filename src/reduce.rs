@@ -0,0 +1,59 @@
+use core::ops::Add;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Vec2D;
+
+impl<T> Vec2D<T> {
+    /// Reduces every row to a single value, by starting from `init` and
+    /// calling `f` with the accumulator and each cell of the row.
+    pub fn reduce_rows<B: Clone>(&self, init: B, mut f: impl FnMut(&mut B, &T)) -> Vec<B> {
+        self.iter_rows()
+            .map(|row| {
+                let mut acc = init.clone();
+                row.iter().for_each(|cell| f(&mut acc, cell));
+                acc
+            })
+            .collect()
+    }
+
+    /// Reduces every column to a single value, by starting from `init` and
+    /// calling `f` with the accumulator and each cell of the column.
+    ///
+    /// The grid is traversed in row-major order, updating all column
+    /// accumulators at once, instead of striding through memory column by
+    /// column.
+    pub fn reduce_cols<B: Clone>(&self, init: B, mut f: impl FnMut(&mut B, &T)) -> Vec<B> {
+        let mut accs = vec![init; self.width];
+        for row in self.iter_rows() {
+            accs.iter_mut()
+                .zip(row)
+                .for_each(|(acc, cell)| f(acc, cell));
+        }
+        accs
+    }
+
+    /// Folds every cell into an accumulator, in row-major order, passing the
+    /// cell's `(x, y)` coordinates along with its value.
+    pub fn fold_xy<B>(&self, init: B, mut f: impl FnMut(B, (usize, usize), &T) -> B) -> B {
+        self.iter_xy()
+            .fold(init, |acc, (pos, cell)| f(acc, pos, cell))
+    }
+
+    /// Returns the sum of every row.
+    pub fn row_sums(&self) -> Vec<T>
+    where
+        T: Copy + Default + Add<Output = T>,
+    {
+        self.reduce_rows(T::default(), |acc, &cell| *acc = *acc + cell)
+    }
+
+    /// Returns the sum of every column.
+    pub fn col_sums(&self) -> Vec<T>
+    where
+        T: Copy + Default + Add<Output = T>,
+    {
+        self.reduce_cols(T::default(), |acc, &cell| *acc = *acc + cell)
+    }
+}
@@ -0,0 +1,90 @@
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Rect, Vec2D};
+
+/// Statistics of a single region of a label grid, see
+/// [`Vec2D::region_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegionStats {
+    /// The number of cells in the region.
+    pub area: usize,
+    /// The number of cell edges separating the region from other regions or
+    /// the outside of the grid.
+    pub perimeter: usize,
+    /// The bounding rectangle of the region.
+    pub bounds: Rect,
+}
+
+impl<T> Vec2D<T> {
+    /// Selects the 4-connected region around `seed`, growing it to every
+    /// neighbor for which `accept(current, neighbor)` returns `true`, where
+    /// `current` is the already selected cell it was reached from.
+    ///
+    /// To compare every cell to the seed instead, capture the seed's value in
+    /// `accept`. Returns the coordinates of the region in breadth-first order,
+    /// starting with the seed, or an empty `Vec` if `seed` is out of bounds.
+    pub fn region_grow(
+        &self,
+        seed: (usize, usize),
+        accept: impl Fn(&T, &T) -> bool,
+    ) -> Vec<(usize, usize)> {
+        let Some(idx) = self.index_of(seed.0, seed.1) else {
+            return Vec::new();
+        };
+
+        let mut selected = vec![false; self.cells.len()];
+        selected[idx] = true;
+        let mut queue = VecDeque::from([seed]);
+        let mut region = Vec::new();
+
+        while let Some((x, y)) = queue.pop_front() {
+            region.push((x, y));
+
+            let current = &self[(x, y)];
+            for ((nx, ny), neighbor) in self.neighbors_von_neumann(x, y) {
+                let n = ny * self.width + nx;
+                if !selected[n] && accept(current, neighbor) {
+                    selected[n] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        region
+    }
+
+    /// Treats the grid as a label grid, where every cell holds the label of
+    /// the region it belongs to, and returns the statistics of every label.
+    ///
+    /// Cells with equal labels count towards the same region, even if they
+    /// aren't connected.
+    pub fn region_stats(&self) -> BTreeMap<T, RegionStats>
+    where
+        T: Ord + Clone,
+    {
+        let mut stats: BTreeMap<T, RegionStats> = BTreeMap::new();
+        for ((x, y), label) in self.iter_xy() {
+            let exposed = 4 - self
+                .neighbors_von_neumann(x, y)
+                .filter(|(_, other)| *other == label)
+                .count();
+
+            let region = stats.entry(label.clone()).or_insert(RegionStats {
+                area: 0,
+                perimeter: 0,
+                bounds: Rect::new(x, y, 1, 1),
+            });
+            region.area += 1;
+            region.perimeter += exposed;
+
+            // Cells are visited in row-major order, so `y` only grows.
+            let bounds = &mut region.bounds;
+            let right = (bounds.x + bounds.width).max(x + 1);
+            bounds.x = bounds.x.min(x);
+            bounds.width = right - bounds.x;
+            bounds.height = y + 1 - bounds.y;
+        }
+        stats
+    }
+}
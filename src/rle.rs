@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+impl<T: Clone + PartialEq> Vec2D<T> {
+    /// Encodes all cells in row-major order as `(value, run_length)` pairs.
+    ///
+    /// Runs longer than `u32::MAX` are split into multiple pairs.
+    pub fn rle_encode(&self) -> Vec<(T, u32)> {
+        let mut runs: Vec<(T, u32)> = Vec::new();
+        for cell in &self.cells {
+            match runs.last_mut() {
+                Some((value, count)) if value == cell && *count < u32::MAX => *count += 1,
+                _ => runs.push((cell.clone(), 1)),
+            }
+        }
+        runs
+    }
+
+    /// Constructs a grid of the given width from `(value, run_length)` pairs,
+    /// as produced by [`Vec2D::rle_encode`].
+    ///
+    /// No runs decode into a grid without rows, or a 0x0 grid if
+    /// `width == 0`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `width == 0` but the runs aren't
+    /// empty.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` if the total run length is not a
+    /// multiple of `width`.
+    ///
+    /// Returns `Vec2DErr::TooLarge` if the decoded cells wouldn't fit into
+    /// memory.
+    pub fn rle_decode(runs: &[(T, u32)], width: usize) -> Result<Self, Vec2DErr> {
+        // The runs may come from untrusted input, so the dimensions are
+        // checked before allocating anything.
+        let len = runs
+            .iter()
+            .try_fold(0usize, |len, &(_, count)| len.checked_add(count as usize))
+            .ok_or(Vec2DErr::TooLarge)?;
+        if width == 0 && len != 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        }
+        if !len.is_multiple_of(width.max(1)) {
+            return Err(Vec2DErr::WidthMismatch {
+                expected: width,
+                actual: len,
+            });
+        }
+        Self::checked_len(width, len / width.max(1))?;
+
+        let mut cells = Vec::with_capacity(len);
+        for (value, count) in runs {
+            cells.extend(core::iter::repeat_n(value, *count as usize).cloned());
+        }
+
+        Ok(Self { cells, width })
+    }
+}
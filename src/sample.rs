@@ -0,0 +1,165 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// How [`Vec2D::downscale`] aggregates each block of cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pooling {
+    /// The average of the block.
+    Mean,
+    /// The smallest value of the block.
+    Min,
+    /// The largest value of the block.
+    Max,
+}
+
+impl<T: Copy + Into<f32>> Vec2D<T> {
+    /// Samples the grid at the fractional coordinates `(fx, fy)`, bilinearly
+    /// interpolating between the four surrounding cells.
+    ///
+    /// Integer coordinates hit the cells exactly. Coordinates outside the
+    /// grid are clamped to its edges.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty.
+    pub fn sample_bilinear(&self, fx: f32, fy: f32) -> f32 {
+        let max_x = (self.width - 1) as f32;
+        let max_y = (self.height() - 1) as f32;
+        let (fx, fy) = (fx.clamp(0.0, max_x), fy.clamp(0.0, max_y));
+
+        // The coordinates are non-negative, so truncating is flooring.
+        let (x0, y0) = (fx as usize, fy as usize);
+        let (x1, y1) = (
+            (x0 + 1).min(self.width - 1),
+            (y0 + 1).min(self.height() - 1),
+        );
+        let (tx, ty) = (fx - x0 as f32, fy - y0 as f32);
+
+        let at = |x: usize, y: usize| -> f32 { self.cells[y * self.width + x].into() };
+        let top = at(x0, y0) + (at(x1, y0) - at(x0, y0)) * tx;
+        let bottom = at(x0, y1) + (at(x1, y1) - at(x0, y1)) * tx;
+
+        top + (bottom - top) * ty
+    }
+
+    /// Returns a copy of the grid scaled down by an integer `factor`, where
+    /// every `factor`x`factor` block of cells is aggregated into one using
+    /// `pooling`.
+    ///
+    /// If the dimensions aren't multiples of `factor`, the blocks along the
+    /// right and bottom edges only aggregate the cells they cover.
+    ///
+    /// # Panics
+    /// Panics if `factor == 0`.
+    pub fn downscale(&self, factor: usize, pooling: Pooling) -> Vec2D<f32> {
+        assert!(factor > 0, "Vec2D downscale factor must be non-zero.");
+
+        let width = self.width.div_ceil(factor);
+        let mut cells = Vec::with_capacity(width.saturating_mul(self.height().div_ceil(factor)));
+        let mut counts = vec![0usize; width];
+
+        for band in self.cells.chunks(self.chunk_width().saturating_mul(factor)) {
+            let start = cells.len();
+            let init = match pooling {
+                Pooling::Mean => 0.0,
+                Pooling::Min => f32::INFINITY,
+                Pooling::Max => f32::NEG_INFINITY,
+            };
+            cells.resize(start + width, init);
+            counts.fill(0);
+
+            let out = &mut cells[start..];
+            for row in band.chunks_exact(self.chunk_width()) {
+                for (x, &cell) in row.iter().enumerate() {
+                    let (acc, value) = (&mut out[x / factor], cell.into());
+                    *acc = match pooling {
+                        Pooling::Mean => *acc + value,
+                        Pooling::Min => acc.min(value),
+                        Pooling::Max => acc.max(value),
+                    };
+                    counts[x / factor] += 1;
+                }
+            }
+
+            if pooling == Pooling::Mean {
+                for (acc, &count) in out.iter_mut().zip(&counts) {
+                    *acc /= count as f32;
+                }
+            }
+        }
+
+        Vec2D { cells, width }
+    }
+}
+
+impl<T: Clone> Vec2D<T> {
+    /// Returns a copy of the grid resized to `new_width`x`new_height`, where
+    /// every cell takes the value of the nearest source cell.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `new_width == 0`.
+    ///
+    /// Returns `Vec2DErr::ZeroHeight` if `new_height == 0`.
+    ///
+    /// Returns `Vec2DErr::EmptySource` if the grid is empty.
+    ///
+    /// Returns `Vec2DErr::TooLarge` if the resampled cells wouldn't fit into
+    /// memory.
+    pub fn resample(&self, new_width: usize, new_height: usize) -> Result<Vec2D<T>, Vec2DErr> {
+        if new_width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        } else if new_height == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        } else if self.cells.is_empty() {
+            return Err(Vec2DErr::EmptySource);
+        }
+
+        // Maps the center of a target cell to the source cell containing it.
+        let nearest = |i: usize, from: usize, to: usize| (2 * i + 1) * from / (2 * to);
+        let height = self.height();
+
+        let mut cells = Vec::with_capacity(Self::checked_len(new_width, new_height)?);
+        for y in 0..new_height {
+            let row = &self.cells[nearest(y, height, new_height) * self.width..];
+            cells.extend((0..new_width).map(|x| row[nearest(x, self.width, new_width)].clone()));
+        }
+
+        Ok(Vec2D {
+            cells,
+            width: new_width,
+        })
+    }
+
+    /// Returns a copy of the grid scaled up by an integer `factor`, where
+    /// every cell is replicated into a `factor`x`factor` block.
+    ///
+    /// # Panics
+    /// Panics if `factor == 0`, or if the upscaled cells wouldn't fit into
+    /// memory.
+    pub fn upscale(&self, factor: usize) -> Vec2D<T> {
+        assert!(factor > 0, "Vec2D upscale factor must be non-zero.");
+
+        let len = factor
+            .checked_mul(factor)
+            .and_then(|area| Self::checked_len(self.cells.len(), area).ok())
+            .expect("Vector size overflow.");
+        let width = self
+            .width
+            .checked_mul(factor)
+            .expect("Vector size overflow.");
+        let mut cells = Vec::with_capacity(len);
+        for row in self.iter_rows() {
+            let start = cells.len();
+            for cell in row {
+                cells.extend(core::iter::repeat_n(cell.clone(), factor));
+            }
+            // The remaining rows of the block are copies of the first one.
+            for _ in 1..factor {
+                cells.extend_from_within(start..start + width);
+            }
+        }
+
+        Vec2D { cells, width }
+    }
+}
@@ -0,0 +1,136 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::Vec2D;
+
+/// The version of the serialization schema written by this crate.
+const SCHEMA_VERSION: u32 = 1;
+
+const FIELDS: &[&str] = &["version", "width", "height", "cells"];
+
+/// Serializes the grid using the current versioned schema.
+impl<T: Serialize> Serialize for Vec2D<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Vec2D", FIELDS.len())?;
+        state.serialize_field("version", &SCHEMA_VERSION)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("height", &self.height())?;
+        state.serialize_field("cells", &self.cells)?;
+        state.end()
+    }
+}
+
+/// Deserializes a grid from the versioned schema, or from the legacy
+/// unversioned `cells` + `width` representation.
+///
+/// Unlike a derived implementation, this validates the grid's invariants, and
+/// rejects payloads with a number of cells that doesn't match the dimensions.
+/// Grids without rows, including 0x0 grids, are accepted.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec2D<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Vec2D", FIELDS, FlatVisitor(PhantomData))
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Version,
+    Width,
+    Height,
+    Cells,
+    #[serde(other)]
+    Other,
+}
+
+pub(crate) struct FlatVisitor<T>(pub(crate) PhantomData<T>);
+
+impl<T> FlatVisitor<T> {
+    fn build<E: de::Error>(
+        version: Option<u32>,
+        width: usize,
+        height: Option<usize>,
+        cells: Vec<T>,
+    ) -> Result<Vec2D<T>, E> {
+        match (version, height) {
+            // Legacy payloads have no version, and derive the height from the
+            // number of cells.
+            (None, _) => {}
+            (Some(SCHEMA_VERSION), None) => return Err(E::missing_field("height")),
+            (Some(SCHEMA_VERSION), Some(height)) => {
+                if width.checked_mul(height) != Some(cells.len()) || (width == 0 && height != 0) {
+                    return Err(E::custom(format!(
+                        "invalid Vec2D: expected {}x{} cells, found {}",
+                        width,
+                        height,
+                        cells.len()
+                    )));
+                }
+            }
+            (Some(version), _) => {
+                return Err(E::custom(format!(
+                    "unsupported Vec2D schema version {}",
+                    version
+                )));
+            }
+        }
+
+        Vec2D::from_raw_parts(cells, width)
+            .map_err(|err| E::custom(format!("invalid Vec2D: {}", err)))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for FlatVisitor<T> {
+    type Value = Vec2D<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "struct Vec2D")
+    }
+
+    // Sequences are only produced by non-self-describing formats, which
+    // can't tell the legacy layout apart, so only the current one is accepted.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let width = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let height = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let cells = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+
+        Self::build(Some(version), width, Some(height), cells)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut version = None;
+        let mut width = None;
+        let mut height = None;
+        let mut cells = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Version => version = Some(map.next_value()?),
+                Field::Width => width = Some(map.next_value()?),
+                Field::Height => height = Some(map.next_value()?),
+                Field::Cells => cells = Some(map.next_value()?),
+                Field::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let width = width.ok_or_else(|| de::Error::missing_field("width"))?;
+        let cells = cells.ok_or_else(|| de::Error::missing_field("cells"))?;
+        Self::build(version, width, height, cells)
+    }
+}
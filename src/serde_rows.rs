@@ -0,0 +1,75 @@
+//! An alternate serde representation of [`Vec2D`] as an array of rows.
+//!
+//! By default, a grid is serialized as a flat list of cells along with its
+//! dimensions, which is hard to read in formats like JSON. This module
+//! serializes a grid as an array of row arrays instead, and deserializes
+//! either form. Use it
+//! with `#[serde(with = "vector_2d::serde_rows")]` on a `Vec2D` field.
+//!
+//! Since the input form is detected while deserializing, this only works
+//! with self-describing formats (JSON, YAML, RON, ...).
+//!
+//! Grids without rows are serialized in the default representation, since an
+//! empty array of rows can't preserve their width.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use alloc::vec::Vec;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Serialize, Serializer};
+
+use crate::Vec2D;
+use crate::serde_impl::FlatVisitor;
+
+/// Serializes a grid as an array of rows.
+pub fn serialize<T, S>(grid: &Vec2D<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    if grid.height() == 0 {
+        return grid.serialize(serializer);
+    }
+
+    serializer.collect_seq(grid.iter_rows())
+}
+
+/// Deserializes a grid from either an array of rows, or the default
+/// representation.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec2D<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(RowsVisitor(PhantomData))
+}
+
+struct RowsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RowsVisitor<T> {
+    type Value = Vec2D<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of rows, or struct Vec2D")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // The size hint comes from the input, so it isn't trusted for
+        // preallocating.
+        let mut rows = Vec::new();
+        while let Some(row) = seq.next_element()? {
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Ok(Vec2D::default());
+        }
+
+        Vec2D::from_rows(rows).map_err(de::Error::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        FlatVisitor(PhantomData).visit_map(map)
+    }
+}
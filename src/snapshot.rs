@@ -0,0 +1,151 @@
+use core::fmt;
+
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// The magic bytes at the start of every snapshot.
+const MAGIC: [u8; 4] = *b"V2DS";
+
+/// The size of the snapshot header: magic, width, height, and cell size.
+const HEADER_SIZE: usize = 4 + 8 + 8 + 4;
+
+/// A cell type with a fixed-size, little-endian binary representation, which
+/// can be stored in a snapshot.
+pub trait SnapshotCell: Copy {
+    /// The number of bytes a single cell takes up.
+    const SIZE: usize;
+
+    /// Appends the little-endian bytes of the cell to `out`.
+    fn write_le(self, out: &mut Vec<u8>);
+
+    /// Reads a cell from exactly `SIZE` little-endian bytes.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_snapshot_cell {
+    ($($t:ty),*) => {$(
+        impl SnapshotCell for $t {
+            const SIZE: usize = size_of::<$t>();
+
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                // Callers always pass exactly `SIZE` bytes.
+                <$t>::from_le_bytes(bytes.try_into().expect("cell size matches"))
+            }
+        }
+    )*};
+}
+impl_snapshot_cell!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// An error produced while reading a grid from a binary snapshot.
+#[derive(Debug)]
+pub enum SnapshotError {
+    BadMagic,
+    CellSizeMismatch { size: usize, expected: usize },
+    Grid(Vec2DErr),
+    LengthMismatch { length: usize, expected: usize },
+}
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+impl From<Vec2DErr> for SnapshotError {
+    fn from(value: Vec2DErr) -> Self {
+        SnapshotError::Grid(value)
+    }
+}
+
+impl<T: SnapshotCell> Vec2D<T> {
+    /// Writes the grid into a compact binary snapshot.
+    ///
+    /// The snapshot starts with a header made of the magic bytes `V2DS`, the
+    /// width and height as `u64`s, and the cell size as a `u32`, followed by
+    /// the raw cells in row-major order. All values are little-endian.
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_SIZE + self.cells.len() * T::SIZE);
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&(self.width as u64).to_le_bytes());
+        out.extend_from_slice(&(self.height() as u64).to_le_bytes());
+        out.extend_from_slice(&(T::SIZE as u32).to_le_bytes());
+
+        for &cell in &self.cells {
+            cell.write_le(&mut out);
+        }
+        out
+    }
+
+    /// Reads a grid from a binary snapshot produced by [`Vec2D::to_snapshot`].
+    ///
+    /// # Errors
+    /// Returns `SnapshotError::BadMagic` if the data doesn't start with a
+    /// snapshot header.
+    ///
+    /// Returns `SnapshotError::CellSizeMismatch` with the snapshot's cell size
+    /// if the snapshot was written for a different cell type.
+    ///
+    /// Returns `SnapshotError::LengthMismatch` with the length of the data if
+    /// the data is truncated or has trailing bytes.
+    ///
+    /// Returns `SnapshotError::Grid` if the stored dimensions don't form a
+    /// valid grid.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        if bytes.len() < HEADER_SIZE || bytes[..4] != MAGIC {
+            return Err(SnapshotError::BadMagic);
+        }
+
+        let read_u64 = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+        let width = usize::try_from(read_u64(4)).map_err(|_| Vec2DErr::TooLarge)?;
+        let height = usize::try_from(read_u64(12)).map_err(|_| Vec2DErr::TooLarge)?;
+        let cell_size = u32::from_le_bytes(bytes[20..24].try_into().unwrap()) as usize;
+        if cell_size != T::SIZE {
+            return Err(SnapshotError::CellSizeMismatch {
+                size: cell_size,
+                expected: T::SIZE,
+            });
+        }
+
+        let expected = width
+            .checked_mul(height)
+            .and_then(|cells| cells.checked_mul(T::SIZE))
+            .and_then(|len| len.checked_add(HEADER_SIZE))
+            .ok_or(Vec2DErr::TooLarge)?;
+        if bytes.len() != expected {
+            return Err(SnapshotError::LengthMismatch {
+                length: bytes.len(),
+                expected,
+            });
+        }
+
+        if width == 0 && height != 0 {
+            return Err(Vec2DErr::ZeroWidth.into());
+        }
+
+        let cells = bytes[HEADER_SIZE..]
+            .chunks_exact(T::SIZE)
+            .map(T::read_le)
+            .collect();
+        Ok(Self::from_raw_parts(cells, width)?)
+    }
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::BadMagic => write!(f, "Data is not a Vec2D snapshot."),
+            SnapshotError::CellSizeMismatch { size, expected } => write!(
+                f,
+                "Snapshot has cells of {} bytes, but {} were expected.",
+                size, expected
+            ),
+            SnapshotError::Grid(err) => write!(f, "Snapshot is not a valid grid: {}", err),
+            SnapshotError::LengthMismatch { length, expected } => write!(
+                f,
+                "Snapshot is {} bytes long, but {} were expected.",
+                length, expected
+            ),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use alloc::vec::Vec;
+
+use crate::{Grid2D, Grid2DMut, Vec2D, Vec2DErr};
+
+/// A hash-map-backed grid, which only stores the cells that are present.
+///
+/// This uses the same `(x, y)` coordinates as [`Vec2D`], within a fixed
+/// width and height, but memory only grows with the number of present cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid2D<T> {
+    cells: HashMap<(usize, usize), T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> SparseGrid2D<T> {
+    /// Constructs a new grid with no cells present.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `width == 0`.
+    /// Returns `Vec2DErr::ZeroHeight` if `height == 0`.
+    pub fn new(width: usize, height: usize) -> Result<Self, Vec2DErr> {
+        if width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        } else if height == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        }
+
+        Ok(Self {
+            cells: HashMap::new(),
+            width,
+            height,
+        })
+    }
+
+    /// Constructs a sparse grid from a dense one, skipping all cells for
+    /// which `is_empty` returns `true`.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`SparseGrid2D::new`], so grids without
+    /// rows are rejected.
+    pub fn from_dense(grid: &Vec2D<T>, is_empty: impl Fn(&T) -> bool) -> Result<Self, Vec2DErr>
+    where
+        T: Clone,
+    {
+        let mut sparse = Self::new(grid.width(), grid.height())?;
+        sparse.cells = grid
+            .iter_xy()
+            .filter(|(_, cell)| !is_empty(cell))
+            .map(|(pos, cell)| (pos, cell.clone()))
+            .collect();
+
+        Ok(sparse)
+    }
+
+    /// Constructs a dense grid of the same dimensions, filling all missing
+    /// cells with `default`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::TooLarge` if the dense grid wouldn't fit into
+    /// memory.
+    pub fn to_dense(&self, default: T) -> Result<Vec2D<T>, Vec2DErr>
+    where
+        T: Clone,
+    {
+        let len = Vec2D::<T>::checked_len(self.width, self.height)?;
+        let mut grid = Vec2D {
+            cells: alloc::vec![default; len],
+            width: self.width,
+        };
+        for (&pos, cell) in &self.cells {
+            grid[pos] = cell.clone();
+        }
+
+        Ok(grid)
+    }
+
+    /// Returns the width of the grid.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of present cells.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if no cells are present.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns a shared reference to the cell at `(x, y)`, if it is present.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`, if it is present.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    /// Sets the cell at `(x, y)`, returning its previous value, if any.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the coordinates are out of bounds.
+    pub fn insert(&mut self, x: usize, y: usize, value: T) -> Result<Option<T>, Vec2DErr> {
+        if x >= self.width || y >= self.height {
+            return Err(Vec2DErr::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        Ok(self.cells.insert((x, y), value))
+    }
+
+    /// Removes the cell at `(x, y)`, returning its value, if it was present.
+    pub fn remove(&mut self, x: usize, y: usize) -> Option<T> {
+        self.cells.remove(&(x, y))
+    }
+
+    /// Removes all cells.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Iterates over all present cells in arbitrary order, yielding their
+    /// `(x, y)` coordinates and values.
+    pub fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// Iterates mutably over all present cells in arbitrary order, yielding
+    /// their `(x, y)` coordinates and values.
+    pub fn iter_xy_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.cells.iter_mut().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// Returns an iterator over the present cells in the von Neumann
+    /// neighborhood (4-connected) of the cell at `(x, y)`.
+    pub fn neighbors_von_neumann(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Grid2D::neighbors_von_neumann(self, x, y)
+    }
+
+    /// Returns an iterator over the present cells in the Moore neighborhood
+    /// (8-connected) of the cell at `(x, y)`.
+    pub fn neighbors_moore(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Grid2D::neighbors_moore(self, x, y)
+    }
+}
+
+impl<T> Grid2D for SparseGrid2D<T> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&T> {
+        SparseGrid2D::get(self, x, y)
+    }
+
+    // The trait yields cells in row-major order, so the present cells are
+    // sorted instead of visiting every coordinate of the grid.
+    fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let mut cells: Vec<_> = SparseGrid2D::iter_xy(self).collect();
+        cells.sort_unstable_by_key(|&((x, y), _)| (y, x));
+        cells.into_iter()
+    }
+}
+
+impl<T> Grid2DMut for SparseGrid2D<T> {
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        SparseGrid2D::get_mut(self, x, y)
+    }
+}
@@ -34,7 +34,10 @@ mod tests {
 
         assert!(matches!(
             Vec2D::from_vec(data, 3),
-            Err(Vec2DErr::WidthMismatch(5, 3))
+            Err(Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 5
+            })
         ));
     }
 
@@ -270,4 +273,2167 @@ mod tests {
     fn test_overload_safety() {
         let _grid = Vec2D::<u128>::new(usize::MAX, usize::MAX).unwrap();
     }
+
+    #[test]
+    fn extend_trait_appends_rows() {
+        let mut grid = Vec2D::from_vec((0..3).collect(), 3).unwrap();
+
+        Extend::extend(&mut grid, (1..3).map(|y| vec![y * 3, y * 3 + 1, y * 3 + 2]));
+        Extend::extend(&mut grid, [[9, 10, 11]]);
+
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid.cells(), (0..12).collect::<Vec<i32>>());
+
+        // The width is only checked against rows that are yielded.
+        Extend::extend(&mut grid, core::iter::empty::<[i32; 2]>());
+        assert_eq!(grid.height(), 4);
+
+        let mut empty = Vec2D::<i32>::default();
+        Extend::extend(&mut empty, core::iter::empty::<[i32; 2]>());
+        assert_eq!(empty.width(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_trait_panics_on_width_mismatch() {
+        let mut grid = Vec2D::from_vec((0..3).collect(), 3).unwrap();
+
+        Extend::extend(&mut grid, [vec![1, 2]]);
+    }
+
+    #[test]
+    fn from_rows_valid() {
+        let grid = Vec2D::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.cells(), (0..6).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn from_rows_invalid() {
+        assert!(matches!(
+            Vec2D::<u8>::from_rows(vec![]),
+            Err(Vec2DErr::EmptySource)
+        ));
+        assert!(matches!(
+            Vec2D::<u8>::from_rows(vec![vec![], vec![]]),
+            Err(Vec2DErr::ZeroWidth)
+        ));
+        assert!(matches!(
+            Vec2D::from_rows(vec![vec![1, 2, 3], vec![4, 5]]),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn into_rows_and_to_rows_work() {
+        let rows = vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]];
+        let grid = Vec2D::from_rows(rows.clone()).unwrap();
+
+        assert_eq!(grid.to_rows(), rows);
+        assert_eq!(grid.into_rows(), rows);
+    }
+
+    #[test]
+    fn from_columns_transposes_input() {
+        let grid = Vec2D::from_columns(vec![vec![0, 3], vec![1, 4], vec![2, 5]]).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.cells(), (0..6).collect::<Vec<i32>>());
+
+        assert!(matches!(
+            Vec2D::from_columns(vec![vec![1, 2], vec![3]]),
+            Err(Vec2DErr::HeightMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn vcat_stacks_grids() {
+        let top = Vec2D::from_vec((0..4).collect(), 2).unwrap();
+        let bottom = Vec2D::from_vec((4..6).collect(), 2).unwrap();
+
+        let grid = top.vcat(bottom).unwrap();
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.cells(), (0..6).collect::<Vec<i32>>());
+
+        let narrow = Vec2D::from_vec(vec![0], 1).unwrap();
+        assert!(matches!(
+            grid.vcat(narrow),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn hcat_joins_grids_side_by_side() {
+        let left = Vec2D::from_vec(vec![0, 1, 3, 4], 2).unwrap();
+        let right = Vec2D::from_vec(vec![2, 5], 1).unwrap();
+
+        let grid = left.hcat(right).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.cells(), (0..6).collect::<Vec<i32>>());
+
+        let short = Vec2D::from_vec(vec![0], 1).unwrap();
+        assert!(matches!(
+            grid.hcat(short),
+            Err(Vec2DErr::HeightMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn append_grid_works() {
+        let mut grid = Vec2D::from_vec((0..3).collect(), 3).unwrap();
+        let other = Vec2D::from_vec((3..6).collect(), 3).unwrap();
+
+        grid.append_grid_cloned(&other).unwrap();
+        grid.append_grid(other).unwrap();
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.cells(), &[0, 1, 2, 3, 4, 5, 3, 4, 5]);
+
+        let narrow = Vec2D::from_vec(vec![0, 1], 2).unwrap();
+        assert!(matches!(
+            grid.append_grid_cloned(&narrow),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+        assert!(matches!(
+            grid.append_grid(narrow),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+
+        // Grids without rows append nothing, whatever their width.
+        grid.append_grid(Vec2D::default()).unwrap();
+        grid.append_grid_cloned(&Vec2D::empty(2).unwrap()).unwrap();
+        assert_eq!(grid.height(), 3);
+        let grid = grid.vcat(Vec2D::default()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+    }
+
+    #[test]
+    fn split_at_row_works() {
+        let grid = Vec2D::from_vec((0..9).collect(), 3).unwrap();
+
+        let (top, bottom) = grid.clone().split_at_row(1).unwrap();
+        assert_eq!(top.cells(), &[0, 1, 2]);
+        assert_eq!(bottom.cells(), &[3, 4, 5, 6, 7, 8]);
+
+        assert!(matches!(
+            grid.clone().split_at_row(0),
+            Err(Vec2DErr::RowOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            grid.split_at_row(3),
+            Err(Vec2DErr::RowOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn split_at_col_works() {
+        let grid = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+
+        let (left, right) = grid.clone().split_at_col(2).unwrap();
+        assert_eq!(left.width(), 2);
+        assert_eq!(left.cells(), &[0, 1, 3, 4]);
+        assert_eq!(right.width(), 1);
+        assert_eq!(right.cells(), &[2, 5]);
+
+        assert!(matches!(
+            grid.split_at_col(3),
+            Err(Vec2DErr::ColOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn display_prints_rows() {
+        let grid = Vec2D::from_vec((0..6).collect::<Vec<i32>>(), 3).unwrap();
+
+        assert_eq!(grid.to_string(), "0 1 2\n3 4 5");
+        assert_eq!(format!("{:2}", grid), " 0  1  2\n 3  4  5");
+        assert_eq!(grid.display_with("", "|").to_string(), "012|345");
+    }
+
+    #[test]
+    fn render_ascii_works() {
+        let grid = Vec2D::from_vec(vec![true, false, false, true], 2).unwrap();
+
+        let rendered = grid.render_ascii(|&wall| if wall { '#' } else { '.' });
+        assert_eq!(rendered, "#.\n.#\n");
+    }
+
+    #[test]
+    fn from_str_parses_text_block() {
+        let grid: Vec2D<char> = "#..\n.#.\n..#\n".parse().unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid[(2, 1)], '.');
+
+        assert!(matches!(
+            Vec2D::from_str_grid("##\n#\n"),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            Vec2D::from_str_grid(""),
+            Err(Vec2DErr::EmptySource)
+        ));
+    }
+
+    #[test]
+    fn parse_str_converts_cells() {
+        let digit = |c: char| c.to_digit(10).ok_or(c);
+
+        let grid = Vec2D::parse_str("123\n456", digit).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.cells(), &[1, 2, 3, 4, 5, 6]);
+
+        assert!(matches!(
+            Vec2D::parse_str("123\n4x6", digit),
+            Err(ParseError::InvalidCell {
+                line: 2,
+                column: 2,
+                error: 'x'
+            })
+        ));
+        assert!(matches!(
+            Vec2D::parse_str("123\n45", digit),
+            Err(ParseError::WidthMismatch {
+                line: 2,
+                length: 2,
+                expected: 3
+            })
+        ));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn csv_round_trip() {
+        let grid = Vec2D::from_vec((0..6).collect::<Vec<i32>>(), 3).unwrap();
+
+        let mut out = Vec::new();
+        grid.to_csv(&mut out).unwrap();
+        assert_eq!(out, b"0,1,2\n3,4,5\n");
+        assert_eq!(Vec2D::from_csv(&out[..]).unwrap(), grid);
+
+        let mut out = Vec::new();
+        grid.to_csv_with_delimiter(&mut out, b';').unwrap();
+        assert_eq!(
+            Vec2D::from_csv_with_delimiter(&out[..], b';').unwrap(),
+            grid
+        );
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn from_csv_validates_input() {
+        assert!(matches!(
+            Vec2D::<i32>::from_csv(&b"1,2\n3\n"[..]),
+            Err(CsvError::WidthMismatch {
+                row: 2,
+                length: 1,
+                expected: 2
+            })
+        ));
+        assert!(matches!(
+            Vec2D::<i32>::from_csv(&b"1,x\n"[..]),
+            Err(CsvError::InvalidCell {
+                row: 1,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Vec2D::<i32>::from_csv(&b""[..]),
+            Err(CsvError::EmptySource)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_pgm_works() {
+        let grid = Vec2D::from_vec(vec![0u8, 128, 255, 64], 2).unwrap();
+
+        let mut out = Vec::new();
+        grid.write_pgm(&mut out).unwrap();
+        assert_eq!(out, b"P5\n2 2\n255\n\x00\x80\xff\x40");
+
+        let mut out = Vec::new();
+        grid.write_pgm_ascii(&mut out).unwrap();
+        assert_eq!(out, b"P2\n2 2\n255\n0 128\n255 64\n");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_ppm_works() {
+        let grid = Vec2D::from_vec(vec![(255u8, 0u8, 0u8), (0, 0, 255)], 2).unwrap();
+
+        let mut out = Vec::new();
+        grid.write_ppm(&mut out).unwrap();
+        assert_eq!(out, b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\xff");
+
+        let mut out = Vec::new();
+        grid.write_ppm_ascii(&mut out).unwrap();
+        assert_eq!(out, b"P3\n2 1\n255\n255 0 0 0 0 255\n");
+
+        // Plain images limit lines to 70 characters.
+        let wide = Vec2D::new_with_default(30, 2, (255u8, 255u8, 255u8)).unwrap();
+        let mut out = Vec::new();
+        wide.write_ppm_ascii(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.lines().all(|line| line.len() <= 70));
+        let samples = text.lines().skip(3).flat_map(str::split_whitespace);
+        assert_eq!(samples.count(), 30 * 2 * 3);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn image_buffer_round_trip() {
+        use image::{ImageBuffer, Luma, Rgba};
+
+        let grid = Vec2D::from_vec((0..6).map(|v| Luma([v])).collect(), 3).unwrap();
+        let img = ImageBuffer::try_from(grid.clone()).unwrap();
+        assert_eq!(img.dimensions(), (3, 2));
+        assert_eq!(img.get_pixel(2, 1), &Luma([5]));
+        assert_eq!(Vec2D::try_from(img).unwrap(), grid);
+
+        let img = ImageBuffer::from_pixel(2, 4, Rgba([1u8, 2, 3, 4]));
+        let grid = Vec2D::try_from(img).unwrap();
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid[(1, 3)], Rgba([1, 2, 3, 4]));
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn ndarray_round_trip() {
+        use ndarray::{Array2, s};
+
+        let grid = Vec2D::from_vec((0..6).collect::<Vec<i32>>(), 3).unwrap();
+        let array = Array2::from(grid.clone());
+        assert_eq!(array.dim(), (2, 3));
+        assert_eq!(array[[1, 2]], 5);
+        assert_eq!(Vec2D::try_from(array.clone()).unwrap(), grid);
+
+        // Column-major layout
+        let transposed = Vec2D::try_from(array.clone().reversed_axes()).unwrap();
+        assert_eq!(transposed.width(), 2);
+        assert_eq!(transposed.cells(), &[0, 3, 1, 4, 2, 5]);
+
+        // Sliced storage
+        let mut sliced = array;
+        sliced.slice_collapse(s![1.., ..]);
+        assert_eq!(Vec2D::try_from(sliced).unwrap().cells(), &[3, 4, 5]);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_indexing_works() {
+        use glam::{IVec2, UVec2};
+
+        let mut grid = Vec2D::from_vec((0..6).collect::<Vec<i32>>(), 3).unwrap();
+        assert_eq!(grid[UVec2::new(2, 1)], 5);
+
+        grid[UVec2::new(0, 1)] = 42;
+        assert_eq!(grid.get_ivec(IVec2::new(0, 1)), Some(&42));
+        assert_eq!(grid.get_ivec(IVec2::new(-1, 0)), None);
+        assert_eq!(grid.get_ivec_mut(IVec2::new(3, 0)), None);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_round_trip() {
+        let mut grid = Vec2D::from_vec(vec![1u16, 2, 3, 4], 2).unwrap();
+        assert_eq!(grid.as_bytes().len(), 8);
+
+        grid.as_bytes_mut()[..2].copy_from_slice(&42u16.to_ne_bytes());
+        assert_eq!(grid[(0, 0)], 42);
+
+        let copy = Vec2D::<u16>::from_bytes(2, grid.as_bytes()).unwrap();
+        assert_eq!(copy, grid);
+
+        assert!(matches!(
+            Vec2D::<u16>::from_bytes(2, &[0, 0, 0]),
+            Err(Vec2DErr::ByteLengthMismatch {
+                len: 3,
+                cell_size: 2
+            })
+        ));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_rows_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Map {
+            #[serde(with = "crate::serde_rows")]
+            tiles: Vec2D<u8>,
+        }
+
+        let map = Map {
+            tiles: Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap(),
+        };
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"tiles":[[1,2],[3,4]]}"#);
+
+        let parsed: Map = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.tiles, map.tiles);
+
+        let flat: Map = serde_json::from_str(r#"{"tiles":{"cells":[1,2,3,4],"width":2}}"#).unwrap();
+        assert_eq!(flat.tiles, map.tiles);
+
+        assert!(serde_json::from_str::<Map>(r#"{"tiles":[[1,2],[3]]}"#).is_err());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn deserialize_validates_shape() {
+        let grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(serde_json::from_str::<Vec2D<i32>>(&json).unwrap(), grid);
+
+        for invalid in [
+            r#"{"cells":[1,2,3],"width":2}"#,
+            r#"{"cells":[1,2],"width":0}"#,
+            r#"{"cells":[1,2]}"#,
+        ] {
+            assert!(serde_json::from_str::<Vec2D<i32>>(invalid).is_err());
+        }
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serialize_uses_versioned_schema() {
+        let grid = Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(
+            json,
+            r#"{"version":1,"width":3,"height":2,"cells":[1,2,3,4,5,6]}"#
+        );
+
+        // Legacy payloads are migrated on read
+        let legacy = r#"{"cells":[1,2,3,4,5,6],"width":3}"#;
+        assert_eq!(serde_json::from_str::<Vec2D<i32>>(legacy).unwrap(), grid);
+
+        for invalid in [
+            r#"{"version":2,"width":3,"height":2,"cells":[1,2,3,4,5,6]}"#,
+            r#"{"version":1,"width":3,"height":1,"cells":[1,2,3,4,5,6]}"#,
+            r#"{"version":1,"width":3,"cells":[1,2,3,4,5,6]}"#,
+            r#"{"version":1,"width":0,"height":2,"cells":[]}"#,
+        ] {
+            assert!(serde_json::from_str::<Vec2D<i32>>(invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn rle_round_trip() {
+        let grid = Vec2D::from_vec(vec![0, 0, 0, 1, 1, 0, 0, 0, 0], 3).unwrap();
+
+        let runs = grid.rle_encode();
+        assert_eq!(runs, vec![(0, 3), (1, 2), (0, 4)]);
+        assert_eq!(Vec2D::rle_decode(&runs, 3).unwrap(), grid);
+
+        assert!(matches!(
+            Vec2D::rle_decode(&[(0, 4)], 3),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 4
+            })
+        ));
+        assert!(matches!(
+            Vec2D::rle_decode(&[(0, 1)], 0),
+            Err(Vec2DErr::ZeroWidth)
+        ));
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let grid = Vec2D::from_vec(vec![1u16, 2, 3, 0x0405], 2).unwrap();
+
+        let bytes = grid.to_snapshot();
+        assert_eq!(&bytes[..4], b"V2DS");
+        assert_eq!(&bytes[bytes.len() - 2..], &[0x05, 0x04]);
+        assert_eq!(Vec2D::<u16>::from_snapshot(&bytes).unwrap(), grid);
+
+        assert!(matches!(
+            Vec2D::<u32>::from_snapshot(&bytes),
+            Err(SnapshotError::CellSizeMismatch {
+                size: 2,
+                expected: 4
+            })
+        ));
+        assert!(matches!(
+            Vec2D::<u16>::from_snapshot(&bytes[..bytes.len() - 1]),
+            Err(SnapshotError::LengthMismatch {
+                length: 31,
+                expected: 32
+            })
+        ));
+        assert!(matches!(
+            Vec2D::<u16>::from_snapshot(b"nope"),
+            Err(SnapshotError::BadMagic)
+        ));
+    }
+
+    #[test]
+    fn diff_yields_changed_cells() {
+        let a = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+        let mut b = a.clone();
+        b[(1, 0)] = 10;
+        b[(2, 1)] = 20;
+
+        let changes: Vec<_> = a.diff(&b).unwrap().collect();
+        assert_eq!(changes, vec![((1, 0), &1, &10), ((2, 1), &5, &20)]);
+
+        let c = Vec2D::from_vec((0..6).collect(), 2).unwrap();
+        assert!(matches!(
+            a.diff(&c),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn grid_patch_applies_changes() {
+        let old = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+        let mut new = old.clone();
+        new[(0, 0)] = 10;
+        new[(2, 1)] = 20;
+
+        let patch = GridPatch::from_diff(&old, &new).unwrap();
+        assert_eq!(patch.changes(), &[((0, 0), 10), ((2, 1), 20)]);
+
+        let mut grid = old.clone();
+        patch.apply(&mut grid).unwrap();
+        assert_eq!(grid, new);
+
+        let mut invalid = GridPatch::new();
+        invalid.push(0, 0, 1);
+        invalid.push(3, 0, 1);
+        assert!(matches!(
+            invalid.apply(&mut grid),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+        assert_eq!(grid, new);
+    }
+
+    #[test]
+    fn tracked_vec2d_records_writes() {
+        let mut grid = TrackedVec2D::new(Vec2D::from_vec((0..6).collect(), 3).unwrap());
+
+        grid[(2, 1)] = 10;
+        grid.set(0, 0, 20).unwrap();
+        *grid.get_mut(2, 1).unwrap() += 1;
+        assert!(matches!(
+            grid.set(3, 0, 0),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+
+        assert_eq!(grid[(2, 1)], 11);
+        assert_eq!(grid.height(), 2);
+        assert!(grid.is_dirty(0, 0));
+        assert!(!grid.is_dirty(1, 0));
+        assert_eq!(grid.dirty_iter().collect::<Vec<_>>(), vec![(2, 1), (0, 0)]);
+
+        grid.clear_dirty();
+        assert_eq!(grid.dirty_iter().count(), 0);
+        assert_eq!(grid.into_inner().cells(), &[20, 1, 2, 3, 4, 11]);
+    }
+
+    #[test]
+    fn grid_patch_apply_reversible() {
+        let mut grid = Vec2D::from_vec(vec![0, 0, 0], 3).unwrap();
+        let patch: GridPatch<_> = [((1, 0), 1), ((1, 0), 2), ((2, 0), 3)]
+            .into_iter()
+            .collect();
+
+        let reverse = patch.apply_reversible(&mut grid).unwrap();
+        assert_eq!(grid.cells(), &[0, 2, 3]);
+
+        reverse.apply(&mut grid).unwrap();
+        assert_eq!(grid.cells(), &[0, 0, 0]);
+    }
+
+    #[test]
+    fn grid_history_undo_redo() {
+        let mut grid = Vec2D::from_vec(vec![0, 0, 0], 3).unwrap();
+        let mut history = GridHistory::new(2);
+
+        for x in 0..3 {
+            let mut patch = GridPatch::new();
+            patch.push(x, 0, x + 1);
+            history.apply(&mut grid, patch).unwrap();
+        }
+        assert_eq!(grid.cells(), &[1, 2, 3]);
+
+        // Only the last two steps are kept
+        assert!(history.undo(&mut grid).unwrap());
+        assert!(history.undo(&mut grid).unwrap());
+        assert!(!history.undo(&mut grid).unwrap());
+        assert_eq!(grid.cells(), &[1, 0, 0]);
+
+        assert!(history.redo(&mut grid).unwrap());
+        assert_eq!(grid.cells(), &[1, 2, 0]);
+
+        // Recording a new step discards the redo stack
+        let before = grid.clone();
+        grid[(0, 0)] = 9;
+        history.record(&before, &grid).unwrap();
+        assert!(!history.can_redo());
+
+        assert!(history.undo(&mut grid).unwrap());
+        assert_eq!(grid.cells(), &[1, 2, 0]);
+    }
+
+    #[test]
+    fn fixed_grid_works() {
+        let mut grid: Grid<u8, 3, 2> = Grid::new();
+        grid[(2, 1)] = 5;
+        *grid.get_mut(0, 1).unwrap() = 3;
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.cells(), &[0, 0, 0, 3, 0, 5]);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.coords(5), Some((2, 1)));
+
+        let coords: Vec<_> = grid.neighbors_von_neumann(0, 0).map(|(c, _)| c).collect();
+        assert_eq!(coords, vec![(1, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn fixed_grid_converts_to_and_from_vec2d() {
+        let grid = Grid::from_rows([[0, 1, 2], [3, 4, 5]]);
+
+        let vec2d = Vec2D::from(grid);
+        assert_eq!(vec2d.cells(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(grid.to_vec2d(), vec2d);
+
+        let empty = Vec2D::from(Grid::<u8, 3, 0>::from_rows([]));
+        assert_eq!((empty.width(), empty.height()), (3, 0));
+        assert_eq!(Grid::<u8, 0, 2>::new().to_vec2d(), Vec2D::default());
+        assert_eq!(Grid::<_, 3, 2>::try_from(vec2d.clone()).unwrap(), grid);
+
+        assert!(matches!(
+            Grid::<_, 2, 3>::try_from(vec2d),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn view_borrows_region() {
+        let grid = Vec2D::from_vec((0..12).collect(), 4).unwrap();
+
+        let view = grid.view(Rect::new(1, 1, 2, 2)).unwrap();
+        assert_eq!(view[(0, 0)], 5);
+        assert_eq!(view.get(1, 1), Some(&10));
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.to_vec2d().cells(), &[5, 6, 9, 10]);
+
+        assert!(matches!(
+            grid.view(Rect::new(3, 0, 2, 1)),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+        assert!(matches!(
+            grid.view(Rect::new(0, 0, 0, 1)),
+            Err(Vec2DErr::ZeroWidth)
+        ));
+    }
+
+    #[test]
+    fn grid2d_trait_is_shared() {
+        fn sum_neighbors<G: Grid2D<Cell = i32>>(grid: &G, x: usize, y: usize) -> i32 {
+            grid.neighbors_von_neumann(x, y).map(|(_, v)| v).sum()
+        }
+
+        let vec2d = Vec2D::from_vec((0..9).collect(), 3).unwrap();
+        let fixed = Grid::from_rows([[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+        let view = vec2d.view(Rect::new(0, 0, 2, 2)).unwrap();
+
+        assert_eq!(sum_neighbors(&vec2d, 1, 1), 1 + 3 + 5 + 7);
+        assert_eq!(sum_neighbors(&fixed, 1, 1), 1 + 3 + 5 + 7);
+        assert_eq!(sum_neighbors(&view, 1, 1), 1 + 3);
+        assert_eq!(Grid2D::neighbors_moore(&view, 0, 0).count(), 3);
+        assert_eq!(Grid2D::iter_xy(&&view).count(), 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sparse_grid_works() {
+        let mut sparse = SparseGrid2D::new(100, 100).unwrap();
+        sparse.insert(10, 10, 1).unwrap();
+        sparse.insert(11, 10, 2).unwrap();
+        assert!(matches!(
+            sparse.insert(100, 0, 3),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(11, 10), Some(&2));
+
+        sparse.insert(50, 5, 4).unwrap();
+        sparse.insert(0, 99, 5).unwrap();
+        sparse.insert(3, 10, 6).unwrap();
+        let coords: Vec<_> = Grid2D::iter_xy(&sparse).map(|(pos, _)| pos).collect();
+        assert_eq!(coords, vec![(50, 5), (3, 10), (10, 10), (11, 10), (0, 99)]);
+        sparse.remove(50, 5);
+        sparse.remove(0, 99);
+        sparse.remove(3, 10);
+        assert_eq!(sparse.get(12, 10), None);
+
+        let neighbors: Vec<_> = sparse.neighbors_moore(10, 11).collect();
+        assert_eq!(neighbors, vec![((10, 10), &1), ((11, 10), &2)]);
+
+        assert_eq!(sparse.remove(11, 10), Some(2));
+        assert_eq!(sparse.len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sparse_grid_dense_conversions() {
+        let dense = Vec2D::from_vec(vec![0, 5, 0, 0, 0, 7], 3).unwrap();
+
+        let sparse = SparseGrid2D::from_dense(&dense, |&v| v == 0).unwrap();
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(2, 1), Some(&7));
+        assert_eq!(sparse.to_dense(0).unwrap(), dense);
+
+        let huge = SparseGrid2D::<u8>::new(usize::MAX, 3).unwrap();
+        assert!(matches!(huge.to_dense(0), Err(Vec2DErr::TooLarge)));
+
+        assert!(matches!(
+            SparseGrid2D::from_dense(&Vec2D::<u8>::empty(3).unwrap(), |&v| v == 0),
+            Err(Vec2DErr::ZeroHeight)
+        ));
+        assert!(matches!(
+            SparseGrid2D::from_dense(&Vec2D::<u8>::default(), |&v| v == 0),
+            Err(Vec2DErr::ZeroWidth)
+        ));
+    }
+
+    #[test]
+    fn chunked_grid_materializes_lazily() {
+        let mut world = ChunkedGrid::new(4, 4, 0u8).unwrap();
+
+        assert_eq!(*world.get(1000, -1000), 0);
+        assert_eq!(world.chunk_count(), 0);
+
+        world.set(5, -1, 7);
+        *world.get_mut(6, -1) += 1;
+        assert_eq!(*world.get(5, -1), 7);
+        assert_eq!(world.chunk_count(), 1);
+
+        assert_eq!(world.split_coords(5, -1), ((1, -1), (1, 3)));
+        let chunk = world.chunk(1, -1).unwrap();
+        assert_eq!(chunk[(1, 3)], 7);
+        assert_eq!(chunk[(2, 3)], 1);
+
+        let coords: Vec<_> = world.iter_chunks().map(|(pos, _)| pos).collect();
+        assert_eq!(coords, vec![(1, -1)]);
+
+        world.chunk_mut(0, 0)[4 + 2] = 9;
+        assert_eq!(*world.get(2, 1), 9);
+        for (_, cells) in world.iter_chunks_mut() {
+            assert_eq!(cells.len(), 16);
+            cells.fill(3);
+        }
+        assert_eq!(*world.get(7, -4), 3);
+    }
+
+    #[test]
+    fn bitgrid_works() {
+        let mut bits = BitGrid::new(10, 10, false).unwrap();
+        bits.set(9, 9, true).unwrap();
+        bits.set(3, 2, true).unwrap();
+        assert!(matches!(
+            bits.set(10, 0, true),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+
+        assert!(bits[(9, 9)]);
+        assert_eq!(bits.get(3, 2), Some(true));
+        assert_eq!(bits.get(4, 2), Some(false));
+        assert_eq!(bits.get(0, 10), None);
+        assert_eq!(bits.count_ones(), 2);
+
+        bits.fill(true);
+        assert_eq!(bits.count_ones(), 100);
+    }
+
+    #[test]
+    fn bitgrid_converts_to_and_from_vec2d() {
+        let grid = Vec2D::from_vec((0..70).map(|i| i % 3 == 0).collect(), 7).unwrap();
+
+        let bits = BitGrid::from(&grid);
+        assert_eq!(bits.count_ones(), 24);
+        assert_eq!(Vec2D::from(&bits), grid);
+    }
+
+    #[test]
+    fn entry_modifies_cells_in_place() {
+        let mut grid = Vec2D::new_with_default(3, 2, 1).unwrap();
+
+        *grid.entry(1, 1).and_modify(|v| *v += 4).into_mut() *= 2;
+        assert_eq!(grid[(1, 1)], 10);
+
+        let mut entry = grid.entry(2, 0);
+        assert_eq!(entry.coords(), (2, 0));
+        assert_eq!(entry.insert(7), 1);
+        assert_eq!(*entry.get(), 7);
+
+        assert!(matches!(
+            grid.try_entry(3, 0),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn entry_or_insert_on_optional_cells() {
+        let mut grid: Vec2D<Option<u32>> = Vec2D::new(2, 2).unwrap();
+
+        *grid.entry(0, 0).or_insert(5) += 1;
+        *grid.entry(0, 0).or_insert_with(|| unreachable!()) += 1;
+        *grid.entry(1, 1).or_default() += 3;
+        assert_eq!(grid.cells(), &[Some(7), None, None, Some(3)]);
+    }
+
+    #[test]
+    fn get_disjoint_mut_works() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+
+        let (a, b) = grid.get_disjoint_mut((0, 0), (1, 1)).unwrap();
+        core::mem::swap(a, b);
+        assert_eq!(grid.cells(), &[4, 2, 3, 1]);
+
+        assert!(grid.get_disjoint_mut((1, 0), (1, 0)).is_none());
+        assert!(grid.get_disjoint_mut((0, 0), (2, 0)).is_none());
+    }
+
+    #[test]
+    fn swap_exchanges_cells() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+
+        grid.swap((0, 0), (2, 1));
+        grid.swap((1, 0), (1, 0));
+        assert_eq!(grid.cells(), &[6, 2, 3, 4, 5, 1]);
+
+        assert!(matches!(
+            grid.try_swap((0, 0), (0, 2)),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+        assert_eq!(grid.cells(), &[6, 2, 3, 4, 5, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_panics_out_of_bounds() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        grid.swap((0, 0), (2, 0));
+    }
+
+    #[test]
+    fn reverse_row_and_col() {
+        let mut grid = Vec2D::from_vec((0..12).collect(), 4).unwrap();
+
+        grid.reverse_row(1).unwrap();
+        assert_eq!(grid.get_row(1), Some(&[7, 6, 5, 4][..]));
+
+        grid.reverse_col(0).unwrap();
+        assert_eq!(grid.cells(), &[8, 1, 2, 3, 7, 6, 5, 4, 0, 9, 10, 11]);
+
+        assert!(matches!(
+            grid.reverse_row(3),
+            Err(Vec2DErr::RowOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            grid.reverse_col(4),
+            Err(Vec2DErr::ColOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn roll_wraps_around() {
+        let mut grid = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+
+        grid.roll(1, 1);
+        assert_eq!(grid.cells(), &[5, 3, 4, 2, 0, 1]);
+
+        grid.roll(-4, -3);
+        assert_eq!(grid.cells(), &[0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn rotate_row_and_col() {
+        let mut grid = Vec2D::from_vec((0..12).collect(), 3).unwrap();
+
+        grid.rotate_row(0, 1).unwrap();
+        assert_eq!(grid.get_row(0), Some(&[2, 0, 1][..]));
+        grid.rotate_row(0, -4).unwrap();
+        assert_eq!(grid.get_row(0), Some(&[0, 1, 2][..]));
+
+        grid.rotate_col(1, 1).unwrap();
+        assert_eq!(grid.cells(), &[0, 10, 2, 3, 1, 5, 6, 4, 8, 9, 7, 11]);
+        grid.rotate_col(1, -5).unwrap();
+        assert_eq!(grid.cells(), (0..12).collect::<Vec<_>>());
+
+        assert!(matches!(
+            grid.rotate_row(4, 1),
+            Err(Vec2DErr::RowOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            grid.rotate_col(3, 1),
+            Err(Vec2DErr::ColOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn diagonal_iterators() {
+        // 0 1 2
+        // 3 4 5
+        let grid = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+
+        let diagonals: Vec<Vec<_>> = grid
+            .iter_diagonals()
+            .map(|d| d.map(|(_, &v)| v).collect())
+            .collect();
+        assert_eq!(diagonals, vec![vec![3], vec![0, 4], vec![1, 5], vec![2]]);
+
+        let anti: Vec<Vec<_>> = grid
+            .iter_anti_diagonals()
+            .map(|d| d.map(|(pos, _)| pos).collect())
+            .collect();
+        assert_eq!(
+            anti,
+            vec![
+                vec![(0, 0)],
+                vec![(1, 0), (0, 1)],
+                vec![(2, 0), (1, 1)],
+                vec![(2, 1)]
+            ]
+        );
+
+        let empty = Vec2D::<i32>::empty(3).unwrap();
+        assert_eq!(empty.iter_diagonals().count(), 0);
+        assert_eq!(empty.iter_anti_diagonals().count(), 0);
+    }
+
+    #[test]
+    fn spiral_iterator() {
+        // 0 1 2 3
+        // 4 5 6 7
+        // 8 9 10 11
+        let grid = Vec2D::from_vec((0..12).collect(), 4).unwrap();
+        let order: Vec<_> = grid.iter_spiral().map(|(_, &v)| v).collect();
+        assert_eq!(order, vec![0, 1, 2, 3, 7, 11, 10, 9, 8, 4, 5, 6]);
+
+        let column = Vec2D::from_vec((0..3).collect(), 1).unwrap();
+        let order: Vec<_> = column.iter_spiral().map(|(pos, _)| pos).collect();
+        assert_eq!(order, vec![(0, 0), (0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn border_iterators() {
+        let mut grid = Vec2D::new_with_default(4, 3, 0).unwrap();
+
+        for (_, cell) in grid.iter_border_mut() {
+            *cell += 1;
+        }
+        assert_eq!(grid.cells(), &[1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1]);
+        assert_eq!(grid.iter_border().count(), 10);
+        assert!(grid.iter_border().all(|((x, y), _)| grid.is_border(x, y)));
+
+        assert!(!grid.is_border(1, 1));
+        assert!(!grid.is_border(4, 0));
+    }
+
+    #[test]
+    fn iter_rect_works() {
+        let mut grid = Vec2D::from_vec((0..12).collect(), 4).unwrap();
+
+        let cells: Vec<_> = grid.iter_rect(Rect::new(1, 1, 2, 2)).unwrap().collect();
+        assert_eq!(
+            cells,
+            vec![((1, 1), &5), ((2, 1), &6), ((1, 2), &9), ((2, 2), &10)]
+        );
+
+        for (_, cell) in grid.iter_rect_mut(Rect::new(2, 0, 2, 3)).unwrap() {
+            *cell = 0;
+        }
+        assert_eq!(grid.cells(), &[0, 1, 0, 0, 4, 5, 0, 0, 8, 9, 0, 0]);
+
+        assert!(matches!(
+            grid.iter_rect(Rect::new(3, 0, 2, 1)),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+        assert!(matches!(
+            grid.iter_rect(Rect::new(0, 0, 1, 0)),
+            Err(Vec2DErr::ZeroHeight)
+        ));
+    }
+
+    #[test]
+    fn row_ranges() {
+        let grid = Vec2D::from_vec((0..8).collect(), 2).unwrap();
+
+        assert_eq!(grid.rows(1..3), Some(&[2, 3, 4, 5][..]));
+        assert_eq!(grid.rows(4..4), Some(&[][..]));
+        assert_eq!(grid.rows(3..5), None);
+
+        let band: Vec<_> = grid.iter_rows_range(2..4).unwrap().collect();
+        assert_eq!(band, vec![&[4, 5][..], &[6, 7][..]]);
+        assert!(grid.iter_rows_range(0..5).is_none());
+    }
+
+    #[test]
+    fn position_finds_coordinates() {
+        let grid = Vec2D::from_vec("..#.@..@".chars().collect(), 4).unwrap();
+
+        assert_eq!(grid.position_of(&'@'), Some((0, 1)));
+        assert_eq!(grid.position(|&c| c != '.'), Some((2, 0)));
+        assert_eq!(grid.position_of(&'x'), None);
+    }
+
+    #[test]
+    fn find_all_matches() {
+        let grid = Vec2D::from_vec("S..S.S".chars().collect(), 3).unwrap();
+
+        let spawns: Vec<_> = grid.find_all(|&c| c == 'S').map(|(pos, _)| pos).collect();
+        assert_eq!(spawns, vec![(0, 0), (0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn contains_value() {
+        let grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+
+        assert!(grid.contains(&4));
+        assert!(!grid.contains(&5));
+    }
+
+    #[test]
+    fn count_cells() {
+        let grid = Vec2D::from_vec(vec![1, 0, 1, 1, 0, 2], 3).unwrap();
+
+        assert_eq!(grid.count_value(&1), 3);
+        assert_eq!(grid.count_where(|&v| v != 0), 4);
+    }
+
+    #[test]
+    fn min_max_by_coordinates() {
+        let heights = Vec2D::from_vec(vec![3, 9, 1, 9, 1, 4], 3).unwrap();
+
+        assert_eq!(heights.argmax(), Some(((0, 1), &9)));
+        assert_eq!(heights.argmin(), Some(((2, 0), &1)));
+
+        let floats = Vec2D::from_vec(vec![0.5, -2.0, 1.5, 0.0], 2).unwrap();
+        assert_eq!(floats.max_by(f64::total_cmp), Some(((0, 1), &1.5)));
+        assert_eq!(floats.min_by(f64::total_cmp), Some(((1, 0), &-2.0)));
+    }
+
+    #[test]
+    fn row_and_col_reductions() {
+        let grid = Vec2D::from_vec((1..=6).collect(), 3).unwrap();
+
+        assert_eq!(grid.row_sums(), vec![6, 15]);
+        assert_eq!(grid.col_sums(), vec![5, 7, 9]);
+
+        let maxes = grid.reduce_cols(0, |acc, &v| *acc = (*acc).max(v));
+        assert_eq!(maxes, vec![4, 5, 6]);
+        let digits = grid.reduce_rows(String::new(), |acc, v| acc.push_str(&v.to_string()));
+        assert_eq!(digits, vec!["123", "456"]);
+    }
+
+    #[test]
+    fn fold_xy_computes_centroid() {
+        let mask = Vec2D::from_vec(vec![0, 1, 0, 0, 1, 1], 3).unwrap();
+
+        let (sx, sy, n) = mask.fold_xy((0, 0, 0), |(sx, sy, n), (x, y), &m| {
+            (sx + x * m, sy + y * m, n + m)
+        });
+        assert_eq!((sx, sy, n), (4, 2, 3));
+    }
+
+    #[test]
+    fn row_and_col_predicates() {
+        let board = Vec2D::from_vec(vec![1, 1, 1, 0, 1, 0, 0, 1, 0], 3).unwrap();
+
+        assert_eq!(board.row_all(0, |&v| v == 1), Some(true));
+        assert_eq!(board.row_all(1, |&v| v == 1), Some(false));
+        assert_eq!(board.row_any(2, |&v| v == 1), Some(true));
+        assert_eq!(board.col_all(1, |&v| v == 1), Some(true));
+        assert_eq!(board.col_any(2, |&v| v == 0), Some(true));
+
+        assert_eq!(board.row_any(3, |_| true), None);
+        assert_eq!(board.col_all(3, |_| true), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn histogram_counts_values() {
+        let terrain = Vec2D::from_vec("~~^.~.".chars().collect(), 3).unwrap();
+
+        let counts = terrain.histogram();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&'~'], 3);
+        assert_eq!(counts[&'.'], 2);
+
+        let land = terrain.count_by(|&c| c != '~');
+        assert_eq!(land[&true], 3);
+    }
+
+    #[test]
+    fn elementwise_arithmetic() {
+        let a = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let b = Vec2D::from_vec(vec![4, 3, 2, 1], 2).unwrap();
+
+        assert_eq!((&a + &b).cells(), &[5, 5, 5, 5]);
+        assert_eq!((&a - &b).cells(), &[-3, -1, 1, 3]);
+        assert_eq!((a.clone() * b.clone()).cells(), &[4, 6, 6, 4]);
+
+        let wide = Vec2D::from_vec(vec![0; 4], 4).unwrap();
+        let tall = Vec2D::from_vec(vec![0; 6], 2).unwrap();
+        assert!(matches!(
+            a.try_add(&wide),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 4
+            })
+        ));
+        assert!(matches!(
+            a.try_mul(&tall),
+            Err(Vec2DErr::HeightMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn matmul_works() {
+        // 1 2 3
+        // 4 5 6
+        let a = Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+        // 7  8
+        // 9  10
+        // 11 12
+        let b = Vec2D::from_vec(vec![7, 8, 9, 10, 11, 12], 2).unwrap();
+
+        let product = a.matmul(&b).unwrap();
+        assert_eq!(product.width(), 2);
+        assert_eq!(product.cells(), &[58, 64, 139, 154]);
+
+        assert!(matches!(
+            a.matmul(&a),
+            Err(Vec2DErr::HeightMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+
+        let empty = Vec2D::<i32>::default();
+        assert_eq!(empty.matmul(&empty).unwrap(), empty);
+
+        let product = Vec2D::<i32>::empty(3).unwrap().matmul(&b).unwrap();
+        assert_eq!((product.width(), product.height()), (2, 0));
+    }
+
+    #[test]
+    fn transpose_in_place_square() {
+        let mut grid = Vec2D::from_vec((0..9).collect(), 3).unwrap();
+
+        grid.transpose_in_place().unwrap();
+        assert_eq!(grid.cells(), &[0, 3, 6, 1, 4, 7, 2, 5, 8]);
+
+        let mut wide = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+        assert!(matches!(
+            wide.transpose_in_place(),
+            Err(Vec2DErr::HeightMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn rotate_90_in_place_square() {
+        let mut grid = Vec2D::from_vec((0..9).collect(), 3).unwrap();
+        grid.rotate_90_in_place().unwrap();
+        assert_eq!(grid.cells(), &[6, 3, 0, 7, 4, 1, 8, 5, 2]);
+
+        let mut grid = Vec2D::from_vec((0..16).collect(), 4).unwrap();
+        let original = grid.clone();
+        grid.rotate_90_in_place().unwrap();
+        assert_eq!(grid.get_row(0), Some(&[12, 8, 4, 0][..]));
+        for _ in 0..3 {
+            grid.rotate_90_in_place().unwrap();
+        }
+        assert_eq!(grid, original);
+
+        let mut tall = Vec2D::from_vec((0..6).collect(), 2).unwrap();
+        assert!(tall.rotate_90_in_place().is_err());
+    }
+
+    #[test]
+    fn bilinear_sampling() {
+        // 0 10
+        // 20 30
+        let heights = Vec2D::from_vec(vec![0u8, 10, 20, 30], 2).unwrap();
+
+        assert_eq!(heights.sample_bilinear(1.0, 0.0), 10.0);
+        assert_eq!(heights.sample_bilinear(0.5, 0.0), 5.0);
+        assert_eq!(heights.sample_bilinear(0.5, 0.5), 15.0);
+        assert_eq!(heights.sample_bilinear(0.25, 1.0), 22.5);
+
+        // Clamped edges
+        assert_eq!(heights.sample_bilinear(-3.0, 7.0), 20.0);
+        assert_eq!(heights.sample_bilinear(5.0, 0.5), 20.0);
+    }
+
+    #[test]
+    fn resample_nearest_neighbor() {
+        let tiles = Vec2D::from_vec("ab\ncd".lines().flat_map(str::chars).collect(), 2).unwrap();
+
+        let up = tiles.resample(4, 3).unwrap();
+        assert_eq!(up.to_string(), "a a b b\nc c d d\nc c d d");
+
+        let down = up.resample(2, 2).unwrap();
+        assert_eq!(down, tiles);
+
+        assert!(matches!(tiles.resample(0, 1), Err(Vec2DErr::ZeroWidth)));
+    }
+
+    #[test]
+    fn upscale_replicates_cells() {
+        let grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+
+        let big = grid.upscale(2);
+        assert_eq!(big.width(), 4);
+        assert_eq!(
+            big.cells(),
+            &[1, 1, 2, 2, 1, 1, 2, 2, 3, 3, 4, 4, 3, 3, 4, 4]
+        );
+        assert_eq!(grid.upscale(1), grid);
+
+        let empty = Vec2D::<u8>::empty(3).unwrap().upscale(2);
+        assert_eq!((empty.width(), empty.height()), (6, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector size overflow.")]
+    fn upscale_panics_on_width_overflow() {
+        Vec2D::<u8>::empty(usize::MAX / 2 + 1).unwrap().upscale(2);
+    }
+
+    #[test]
+    fn downscale_pools_blocks() {
+        // 1 2 3
+        // 3 4 5
+        // 6 7 8
+        let grid = Vec2D::from_vec(vec![1u8, 2, 3, 3, 4, 5, 6, 7, 8], 3).unwrap();
+
+        let mean = grid.downscale(2, Pooling::Mean);
+        assert_eq!(mean.width(), 2);
+        assert_eq!(mean.cells(), &[2.5, 4.0, 6.5, 8.0]);
+        assert_eq!(
+            grid.downscale(2, Pooling::Min).cells(),
+            &[1.0, 3.0, 6.0, 8.0]
+        );
+        assert_eq!(grid.downscale(3, Pooling::Max).cells(), &[8.0]);
+        assert_eq!(grid.downscale(usize::MAX, Pooling::Max).cells(), &[8.0]);
+    }
+
+    #[test]
+    fn threshold_creates_masks() {
+        let heights = Vec2D::from_vec(vec![0.1, 0.7, 0.5, 0.2], 2).unwrap();
+
+        assert_eq!(heights.threshold(&0.5).cells(), &[false, true, true, false]);
+        assert_eq!(
+            heights.threshold_by(|&h| h < 0.3).cells(),
+            &[true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn apply_where_respects_mask() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let mask = grid.threshold_by(|&v| v % 2 == 0);
+
+        grid.apply_where(&mask, |v| *v *= 10).unwrap();
+        assert_eq!(grid.cells(), &[1, 20, 3, 40]);
+
+        let other = Vec2D::new_with_default(4, 1, true).unwrap();
+        assert!(matches!(
+            grid.apply_where(&other, |_| ()),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn extract_masked_collects_selection() {
+        let grid = Vec2D::from_vec(vec!['a', 'b', 'c', 'd'], 2).unwrap();
+        let mask = Vec2D::from_vec(vec![false, true, true, false], 2).unwrap();
+
+        assert_eq!(
+            grid.extract_masked(&mask).unwrap(),
+            vec![((1, 0), &'b'), ((0, 1), &'c')]
+        );
+
+        let short = Vec2D::from_vec(vec![true, true], 2).unwrap();
+        assert!(matches!(
+            grid.extract_masked(&short),
+            Err(Vec2DErr::HeightMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn gradient_of_heightmap() {
+        // A ramp rising to the right.
+        let ramp = Vec2D::from_vec(vec![0u8, 2, 4, 0, 2, 4], 3).unwrap();
+
+        let grad = ramp.gradient(EdgePolicy::Clamp);
+        assert_eq!(
+            grad.cells(),
+            &[(1.0, 0.0), (2.0, 0.0), (1.0, 0.0)].repeat(2)
+        );
+
+        let wrapped = ramp.gradient(EdgePolicy::Wrap);
+        assert_eq!(wrapped[(0, 0)], (-1.0, 0.0));
+
+        let sobel = ramp.sobel(EdgePolicy::Clamp);
+        assert_eq!(sobel[(1, 0)], (16.0, 0.0));
+    }
+
+    #[test]
+    fn box_blur_averages_neighborhood() {
+        let mut grid = Vec2D::new_with_default(3, 3, 0u8).unwrap();
+        grid[(1, 1)] = 9;
+
+        let blurred = grid.box_blur(1, EdgePolicy::Clamp);
+        assert_eq!(blurred.cells(), &[1.0; 9]);
+
+        let mut grid = Vec2D::new_with_default(5, 1, 0u8).unwrap();
+        grid[(0, 0)] = 3;
+        let blurred = grid.box_blur(1, EdgePolicy::Clamp);
+        assert_eq!(blurred.cells(), &[2.0, 1.0, 0.0, 0.0, 0.0]);
+        let blurred = grid.box_blur(1, EdgePolicy::Wrap);
+        assert_eq!(blurred.cells(), &[1.0, 1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(
+            grid.box_blur(0, EdgePolicy::Wrap).cells(),
+            &[3.0, 0.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn median_filter_removes_speckles() {
+        let mut grid = Vec2D::new_with_default(4, 4, 1).unwrap();
+        grid[(1, 2)] = 100;
+        grid[(3, 0)] = 0;
+
+        let filtered = grid.median_filter(1, EdgePolicy::Clamp);
+        assert_eq!(filtered, Vec2D::new_with_default(4, 4, 1).unwrap());
+        assert_eq!(grid.median_filter(0, EdgePolicy::Wrap), grid);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec2D filter radius is too large.")]
+    fn box_blur_panics_on_huge_radius() {
+        Vec2D::new_with_default(2, 2, 0u8)
+            .unwrap()
+            .box_blur(usize::MAX / 2, EdgePolicy::Clamp);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec2D filter radius is too large.")]
+    fn median_filter_panics_on_huge_radius() {
+        Vec2D::new_with_default(2, 2, 0u8)
+            .unwrap()
+            .median_filter(usize::MAX, EdgePolicy::Wrap);
+    }
+
+    #[test]
+    fn contours_outline_a_peak() {
+        let mut grid = Vec2D::new_with_default(3, 3, 0u8).unwrap();
+        grid[(1, 1)] = 2;
+
+        let contours = grid.contours(1.0);
+        assert_eq!(contours.len(), 1);
+
+        let ring = &contours[0];
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+        for &point in &[(1.0, 0.5), (1.5, 1.0), (1.0, 1.5), (0.5, 1.0)] {
+            assert!(ring.contains(&point));
+        }
+
+        // A step crossing the whole grid yields an open line.
+        let step = Vec2D::from_vec(vec![0u8, 0, 4, 0, 0, 4], 3).unwrap();
+        assert_eq!(step.contours(1.0), vec![vec![(1.25, 0.0), (1.25, 1.0)]]);
+    }
+
+    #[test]
+    fn region_grow_selects_connected_cells() {
+        // 1 1 5
+        // 2 9 5
+        // 3 4 5
+        let grid = Vec2D::from_vec(vec![1, 1, 5, 2, 9, 5, 3, 4, 5], 3).unwrap();
+
+        let same = grid.region_grow((2, 2), |a, b| a == b);
+        assert_eq!(same, vec![(2, 2), (2, 1), (2, 0)]);
+
+        // Gradual slope: each step may rise by at most one.
+        let slope = grid.region_grow((0, 0), |a, b| b - a <= 1 && b >= a);
+        assert_eq!(slope.len(), 8);
+        assert!(!slope.contains(&(1, 1)));
+
+        assert!(grid.region_grow((3, 0), |_, _| true).is_empty());
+    }
+
+    #[test]
+    fn region_stats_per_label() {
+        // a a b
+        // a b b
+        let labels = Vec2D::from_vec("aababb".chars().collect(), 3).unwrap();
+
+        let stats = labels.region_stats();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(
+            stats[&'a'],
+            RegionStats {
+                area: 3,
+                perimeter: 8,
+                bounds: Rect::new(0, 0, 2, 2),
+            }
+        );
+        assert_eq!(stats[&'b'].area, 3);
+        assert_eq!(stats[&'b'].bounds, Rect::new(1, 0, 2, 2));
+    }
+
+    #[test]
+    fn cursor_navigates_grid() {
+        // a b
+        // c d
+        let grid = Vec2D::from_vec(vec!['a', 'b', 'c', 'd'], 2).unwrap();
+        let mut cursor = grid.cursor(0, 0).unwrap();
+
+        assert_eq!(cursor.peek(Direction::East), Some(&'b'));
+        assert_eq!(cursor.peek(Direction::North), None);
+        assert_eq!(cursor.move_south().unwrap(), &'c');
+        assert_eq!(cursor.move_east().unwrap(), &'d');
+        assert!(matches!(
+            cursor.move_east(),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+        assert_eq!(cursor.pos(), (1, 1));
+        assert_eq!(cursor.step(Direction::North).unwrap(), &'b');
+        assert_eq!(cursor.get(), &'b');
+
+        assert!(grid.cursor(2, 0).is_err());
+    }
+
+    #[test]
+    fn direction_helpers() {
+        assert_eq!(Direction::NorthEast.offset(), (1, -1));
+        assert_eq!(Direction::West.opposite(), Direction::East);
+        assert_eq!(Direction::SouthWest.opposite(), Direction::NorthEast);
+        assert_eq!(Direction::North.rotate_cw(), Direction::East);
+        assert_eq!(Direction::NorthWest.rotate_ccw(), Direction::SouthWest);
+        assert!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count() == 4);
+
+        let grid = Vec2D::new_with_default(3, 2, 0).unwrap();
+        assert_eq!(grid.step((0, 0), Direction::SouthEast), Some((1, 1)));
+        assert_eq!(grid.step((0, 0), Direction::North), None);
+        assert_eq!(grid.step((2, 1), Direction::East), None);
+    }
+
+    #[test]
+    fn walk_along_direction() {
+        // . . # .
+        // . . . .
+        let grid = Vec2D::from_vec("..#.....".chars().collect(), 4).unwrap();
+
+        let cells: Vec<_> = grid
+            .walk((0, 0), Direction::East)
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(cells, vec![(1, 0), (2, 0), (3, 0)]);
+        assert_eq!(grid.walk((0, 1), Direction::SouthEast).count(), 0);
+
+        // A rock sliding east stops in front of the wall.
+        let free: Vec<_> = grid
+            .walk_while((0, 0), Direction::East, |&c| c == '.')
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(free, vec![(1, 0)]);
+    }
+
+    #[test]
+    fn rect_operations() {
+        let a = Rect::new(1, 1, 3, 2);
+        let b = Rect::new(2, 0, 5, 2);
+
+        assert!(a.contains(3, 2));
+        assert!(!a.contains(4, 1));
+        assert!(!a.contains(0, 1));
+        assert_eq!(a.intersect(&b), Some(Rect::new(2, 1, 2, 1)));
+        assert_eq!(a.intersect(&Rect::new(4, 0, 1, 5)), None);
+
+        let coords: Vec<_> = Rect::new(1, 0, 2, 2).iter_coords().collect();
+        assert_eq!(coords, vec![(1, 0), (2, 0), (1, 1), (2, 1)]);
+        let coords: Vec<_> = Rect::new(usize::MAX, 0, 2, 1).iter_coords().collect();
+        assert_eq!(coords, vec![]);
+
+        // Clipping a region to the grid before iterating over it.
+        let grid = Vec2D::new_with_default(4, 3, 1).unwrap();
+        let clipped = grid.bounds().intersect(&Rect::new(2, 1, 10, 10)).unwrap();
+        assert_eq!(grid.iter_rect(clipped).unwrap().count(), 4);
+    }
+
+    #[test]
+    fn fill_rect_and_crop() {
+        let mut grid = Vec2D::new_with_default(4, 3, 0).unwrap();
+
+        grid.fill_rect(Rect::new(1, 1, 2, 2), 5).unwrap();
+        assert_eq!(grid.cells(), &[0, 0, 0, 0, 0, 5, 5, 0, 0, 5, 5, 0]);
+
+        let cropped = grid.crop(Rect::new(1, 0, 3, 2)).unwrap();
+        assert_eq!(cropped.width(), 3);
+        assert_eq!(cropped.cells(), &[0, 0, 0, 5, 5, 0]);
+
+        assert!(matches!(
+            grid.fill_rect(Rect::new(3, 0, 2, 1), 1),
+            Err(Vec2DErr::OutOfBounds { .. })
+        ));
+        assert!(matches!(
+            grid.crop(Rect::new(0, 0, 1, 0)),
+            Err(Vec2DErr::ZeroHeight)
+        ));
+    }
+
+    #[test]
+    fn coord_arithmetic_and_indexing() {
+        let a = Coord::new(1, 4);
+        let b = Coord::from((3, 1));
+
+        assert_eq!(a + b, Coord::new(4, 5));
+        assert_eq!((a + b) - a, b);
+        assert_eq!(a.offset(-1, 2), Some(Coord::new(0, 6)));
+        assert_eq!(a.offset(-2, 0), None);
+        assert_eq!(b.step(Direction::NorthWest), Some(Coord::new(2, 0)));
+        assert_eq!(a + (-1, 2), Some(Coord::new(0, 6)));
+        assert_eq!(a + (0, isize::MIN), None);
+        assert_eq!(b + Direction::NorthWest, Some(Coord::new(2, 0)));
+        assert_eq!(b - Direction::South, Some(Coord::new(3, 0)));
+        assert_eq!(Coord::new(0, 0) + Direction::West, None);
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+
+        let mut grid = Vec2D::new_with_default(2, 2, 0).unwrap();
+        grid[Coord::new(1, 0)] = 7;
+        assert_eq!(grid[(1, 0)], 7);
+        assert_eq!(<(usize, usize)>::from(Coord::new(1, 0)), (1, 0));
+    }
+
+    #[test]
+    fn get_signed_rejects_negatives() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+
+        assert_eq!(grid.get_signed(1, 1), Some(&4));
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(0, 2), None);
+
+        *grid.get_signed_mut(0, 1).unwrap() = 9;
+        assert_eq!(grid[(0, 1)], 9);
+        assert!(grid.get_signed_mut(isize::MIN, 0).is_none());
+    }
+
+    #[test]
+    fn index_with_arrays_and_tuple_refs() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+
+        grid[[1, 0]] += 10;
+        assert_eq!(grid[[1, 0]], 12);
+
+        let targets = [(0, 1), (1, 1)];
+        for pos in &targets {
+            grid[pos] = 0;
+        }
+        assert_eq!(grid.cells(), &[1, 12, 0, 0]);
+        assert_eq!(grid[&(1, 0)], 12);
+    }
+
+    #[test]
+    fn index_rows_with_usize() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+
+        assert_eq!(&grid[1], &[4, 5, 6]);
+        grid[0][2] = 9;
+        assert_eq!(grid[(2, 0)], 9);
+        grid[1].reverse();
+        assert_eq!(grid.cells(), &[1, 2, 9, 6, 5, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_row_out_of_bounds() {
+        let grid = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let _ = &grid[2];
+    }
+
+    #[test]
+    fn coords_of_inverts_index_of() {
+        let grid = Vec2D::new_with_default(4, 3, 0).unwrap();
+
+        for ((x, y), _) in grid.iter_xy() {
+            let idx = grid.index_of(x, y).unwrap();
+            assert_eq!(grid.coords_of(idx), Some((x, y)));
+        }
+        assert_eq!(grid.coords_of(12), None);
+    }
+
+    #[test]
+    fn raw_parts_round_trip() {
+        let grid = Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+
+        let (mut cells, width) = grid.into_raw_parts();
+        cells.iter_mut().for_each(|v| *v *= 2);
+        let grid = Vec2D::from_raw_parts(cells, width).unwrap();
+        assert_eq!(grid.get_row(1), Some(&[8, 10, 12][..]));
+
+        let empty = Vec2D::<u8>::from_raw_parts(Vec::new(), 3).unwrap();
+        assert_eq!(empty.height(), 0);
+
+        assert!(matches!(
+            Vec2D::from_raw_parts(vec![1, 2, 3], 2),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn boxed_slice_conversions() {
+        let boxed: Box<[u8]> = vec![1, 2, 3, 4].into_boxed_slice();
+        let grid = Vec2D::from_boxed(boxed, 2).unwrap();
+        assert_eq!(grid[(1, 1)], 4);
+
+        let (cells, width) = grid.into_boxed_cells();
+        assert_eq!((&*cells, width), (&[1, 2, 3, 4][..], 2));
+
+        let empty: Box<[u8]> = Box::new([]);
+        assert!(matches!(
+            Vec2D::from_boxed(empty, 2),
+            Err(Vec2DErr::EmptySource)
+        ));
+    }
+
+    #[test]
+    fn row_capacity() {
+        let mut grid = Vec2D::with_capacity_rows(3, 4).unwrap();
+        assert_eq!(grid.height(), 0);
+        assert!(grid.capacity_rows() >= 4);
+
+        grid.extend_cloned(&[1, 2, 3]).unwrap();
+        grid.reserve_rows(10);
+        assert!(grid.capacity_rows() >= 11);
+        assert_eq!(grid.height(), 1);
+
+        assert!(matches!(
+            Vec2D::<u8>::with_capacity_rows(0, 4),
+            Err(Vec2DErr::ZeroWidth)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector size overflow.")]
+    fn reserve_rows_panics_on_overflow() {
+        let mut grid = Vec2D::from_vec(vec![1u8, 2, 3], 3).unwrap();
+        grid.reserve_rows(usize::MAX);
+    }
+
+    #[test]
+    fn shrink_to_fit_releases_capacity() {
+        let mut grid = Vec2D::new_with_default(4, 100, 0u8).unwrap();
+        grid.truncate_rows(2).unwrap();
+        assert!(grid.capacity_rows() >= 100);
+
+        grid.shrink_to_fit();
+        assert_eq!(grid.capacity_rows(), 2);
+        assert_eq!(grid.height(), 2);
+    }
+
+    #[test]
+    fn resize_width_policies() {
+        let mut grid = Vec2D::from_vec((1..=6).collect(), 3).unwrap();
+
+        grid.resize_width(2, ResizePolicy::Reflow).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert!(matches!(
+            grid.resize_width(4, ResizePolicy::Reflow),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 4,
+                actual: 6
+            })
+        ));
+
+        grid.resize_width(3, ResizePolicy::PadTruncate(0)).unwrap();
+        assert_eq!(grid.cells(), &[1, 2, 0, 3, 4, 0, 5, 6, 0]);
+        grid.resize_width(1, ResizePolicy::PadTruncate(0)).unwrap();
+        assert_eq!(grid.cells(), &[1, 3, 5]);
+
+        assert!(matches!(
+            grid.resize_width(0, ResizePolicy::Reflow),
+            Err(Vec2DErr::ZeroWidth)
+        ));
+    }
+
+    #[test]
+    fn swap_remove_row_moves_last_row() {
+        let mut grid = Vec2D::from_vec((0..8).collect(), 2).unwrap();
+
+        assert_eq!(grid.swap_remove_row(1).unwrap(), vec![2, 3]);
+        assert_eq!(grid.cells(), &[0, 1, 6, 7, 4, 5]);
+        assert_eq!(grid.swap_remove_row(2).unwrap(), vec![4, 5]);
+        assert_eq!(grid.cells(), &[0, 1, 6, 7]);
+        assert!(matches!(
+            grid.swap_remove_row(2),
+            Err(Vec2DErr::RowOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn sort_rows() {
+        let mut grid = Vec2D::from_vec(vec![3, 0, 1, 9, 2, 5, 1, 1], 2).unwrap();
+
+        grid.sort_rows_by_key(|row| row[0]);
+        assert_eq!(grid.cells(), &[1, 9, 1, 1, 2, 5, 3, 0]);
+
+        grid.sort_rows_by(|a, b| b[1].cmp(&a[1]));
+        assert_eq!(grid.cells(), &[1, 9, 2, 5, 1, 1, 3, 0]);
+
+        let mut big = Vec2D::from_vec((0..40).rev().collect(), 1).unwrap();
+        big.sort_rows_by_key(|row| row[0]);
+        assert_eq!(big.cells(), (0..40).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn partition_rows_by_predicate() {
+        let grid = Vec2D::from_vec(vec![1, 2, 0, 0, 3, 4, 0, 5], 2).unwrap();
+
+        let (full, holes) = grid.clone().partition_rows(|row| !row.contains(&0));
+        assert_eq!(full.unwrap().cells(), &[1, 2, 3, 4]);
+        assert_eq!(holes.unwrap().cells(), &[0, 0, 0, 5]);
+
+        let (all, none) = grid.partition_rows(|_| true);
+        assert_eq!(all.unwrap().height(), 4);
+        assert!(none.is_none());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_grids_respect_dimensions() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..10 {
+            let grid = Vec2D::<u8>::arbitrary(&mut u).unwrap();
+            assert!((1..=16).contains(&grid.width()));
+            assert!((1..=16).contains(&grid.height()));
+
+            let grid = Vec2D::<u8>::arbitrary_with_dimensions(&mut u, 2..=3, 0..=2).unwrap();
+            assert!((2..=3).contains(&grid.width()));
+            assert!((1..=2).contains(&grid.height()));
+        }
+
+        assert!(Vec2D::<u8>::arbitrary_with_dimensions(&mut u, 0..=0, 1..=1).is_err());
+        let huge = usize::MAX..=usize::MAX;
+        assert!(Vec2D::<u8>::arbitrary_with_dimensions(&mut u, huge.clone(), huge).is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+        assert!(grid.validate().is_ok());
+
+        grid.insert_row(1, vec![7, 8, 9]).unwrap();
+        grid.truncate_cols(2).unwrap();
+        assert!(grid.validate().is_ok());
+
+        grid.clear();
+        assert!(grid.validate().is_ok());
+        assert!(
+            Vec2D::<u8>::from_raw_parts(vec![], 3)
+                .unwrap()
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn errors_carry_context() {
+        let mut grid = Vec2D::from_vec(vec![0; 6], 3).unwrap();
+        assert_eq!(
+            grid.try_entry(3, 1).unwrap_err(),
+            Vec2DErr::OutOfBounds {
+                x: 3,
+                y: 1,
+                width: 3,
+                height: 2
+            }
+        );
+        assert_eq!(
+            grid.remove_row(2).unwrap_err(),
+            Vec2DErr::RowOutOfBounds { y: 2, height: 2 }
+        );
+        assert_eq!(
+            grid.reverse_col(5).unwrap_err(),
+            Vec2DErr::ColOutOfBounds { x: 5, width: 3 }
+        );
+        assert_eq!(
+            grid.extend(vec![1, 2]).unwrap_err(),
+            Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 2
+            }
+        );
+        assert_eq!(
+            grid.remove_row(2).unwrap_err().to_string(),
+            "Row 2 is out of bounds of a grid of height 2."
+        );
+    }
+
+    #[test]
+    fn oversized_grids_are_rejected() {
+        assert_eq!(
+            Vec2D::<u8>::new(usize::MAX, 2).unwrap_err(),
+            Vec2DErr::TooLarge
+        );
+        assert_eq!(
+            Vec2D::new_with_default(usize::MAX / 2, 2, 0u16).unwrap_err(),
+            Vec2DErr::TooLarge
+        );
+        assert_eq!(
+            Vec2D::<u8>::with_capacity_rows(2, usize::MAX).unwrap_err(),
+            Vec2DErr::TooLarge
+        );
+
+        let mut grid = Vec2D::from_vec(vec![0u8; 4], 2).unwrap();
+        assert_eq!(
+            grid.resize_width(usize::MAX, ResizePolicy::PadTruncate(0)),
+            Err(Vec2DErr::TooLarge)
+        );
+        assert_eq!(grid.cells(), [0; 4]);
+    }
+
+    #[test]
+    fn empty_grids() {
+        let mut grid = Vec2D::<i32>::default();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.iter_rows().count(), 0);
+        assert!(grid.get(0, 0).is_none());
+
+        grid.extend(vec![1, 2, 3]).unwrap();
+        grid.extend_cloned(&[4, 5, 6]).unwrap();
+        assert_eq!(grid, Vec2D::from_vec((1..=6).collect(), 3).unwrap());
+
+        let mut grid = Vec2D::empty(2).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 0));
+        assert!(matches!(
+            grid.extend(vec![1, 2, 3]),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+        Extend::extend(&mut grid, [[1, 2], [3, 4]]);
+        assert_eq!(grid.height(), 2);
+
+        grid.clear();
+        assert_eq!(grid.height(), 0);
+        Extend::extend(&mut grid, [vec![7, 8, 9]]);
+        assert_eq!(grid.width(), 3);
+        assert!(matches!(Vec2D::<u8>::empty(0), Err(Vec2DErr::ZeroWidth)));
+    }
+
+    #[test]
+    fn insert_row_at_end() {
+        let mut grid = Vec2D::from_vec((0..3).collect(), 3).unwrap();
+
+        grid.insert_row(1, vec![3, 4, 5]).unwrap();
+        grid.insert_row_cloned(2, &[6, 7, 8]).unwrap();
+        assert_eq!(grid.cells(), (0..9).collect::<Vec<_>>());
+
+        assert_eq!(
+            grid.insert_row(4, vec![0, 0, 0]),
+            Err(Vec2DErr::RowOutOfBounds { y: 4, height: 3 })
+        );
+        assert_eq!(
+            grid.insert_row_cloned(4, &[0, 0, 0]),
+            Err(Vec2DErr::RowOutOfBounds { y: 4, height: 3 })
+        );
+
+        let mut grid = Vec2D::default();
+        grid.insert_row(0, vec![1, 2]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 1));
+    }
+
+    #[test]
+    fn insert_rows_from_iterators() {
+        let mut grid = Vec2D::from_vec(vec![0, 1, 6, 7], 2).unwrap();
+
+        grid.insert_rows(1, 2..6).unwrap();
+        assert_eq!(grid.cells(), (0..8).collect::<Vec<_>>());
+        assert!(matches!(
+            grid.insert_rows(0, 0..3),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+
+        grid.insert_rows_from_iter(4, (8..12).step_by(2).map(|x| [x, x + 1]))
+            .unwrap();
+        assert_eq!(grid.cells(), (0..12).collect::<Vec<_>>());
+        assert!(matches!(
+            grid.insert_rows_from_iter(0, [vec![0, 0], vec![0]]),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert_eq!(grid.cells(), (0..12).collect::<Vec<_>>());
+
+        let mut grid = Vec2D::default();
+        grid.insert_rows_from_iter(0, (0..2).map(|y| (0..3).map(move |x| y * 3 + x)))
+            .unwrap();
+        assert_eq!(grid, Vec2D::from_vec((0..6).collect(), 3).unwrap());
+
+        let mut grid = Vec2D::default();
+        assert!(matches!(
+            grid.insert_rows_from_iter(0, [vec![], vec![1, 2, 3]]),
+            Err(Vec2DErr::ZeroWidth)
+        ));
+        assert_eq!(grid.width(), 0);
+        grid.insert_rows(0, [1, 2, 3]).unwrap();
+        assert_eq!(grid, Vec2D::from_vec(vec![1, 2, 3], 3).unwrap());
+    }
+
+    #[test]
+    fn into_iter_rows_yields_owned_rows() {
+        let grid = Vec2D::from_vec((0..6).map(|x| x.to_string()).collect(), 3).unwrap();
+
+        let mut rows = grid.into_iter_rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows.next().unwrap(), ["0", "1", "2"]);
+        assert_eq!(rows.next().unwrap(), ["3", "4", "5"]);
+        assert!(rows.next().is_none());
+
+        assert_eq!(Vec2D::<u8>::default().into_iter_rows().count(), 0);
+    }
+
+    #[test]
+    fn enumerate_cells_yields_indices() {
+        let mut grid = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+
+        for (idx, (x, y), &cell) in grid.enumerate_cells() {
+            assert_eq!(idx, cell);
+            assert_eq!(grid.index_of(x, y), Some(idx));
+        }
+
+        grid.enumerate_cells_mut()
+            .for_each(|(idx, (x, y), cell)| *cell = idx * 10 + x + y);
+        assert_eq!(grid.cells(), [0, 11, 22, 31, 42, 53]);
+    }
+
+    #[test]
+    fn cell_grid_allows_writing_while_reading() {
+        let mut grid = Vec2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+
+        let cells = grid.as_cell_grid();
+        assert_eq!((cells.width(), cells.height()), (3, 2));
+        for y in 0..cells.height() {
+            for x in 1..cells.width() {
+                let left = cells[(x - 1, y)].get();
+                cells[(x, y)].set(cells[(x, y)].get() + left);
+            }
+        }
+        let sum: i32 = cells
+            .neighbors_von_neumann(1, 1)
+            .map(|(_, cell)| cell.get())
+            .sum();
+
+        assert_eq!(sum, 3 + 4 + 15);
+        assert_eq!(grid.cells(), [1, 3, 6, 4, 9, 15]);
+    }
+
+    #[test]
+    fn atomic_grids_share_updates() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let grid: Vec2D<AtomicU32> = Vec2D::new(4, 2).unwrap().into_atomic();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for cell in grid.cells() {
+                        cell.fetch_add(1, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(grid.load_grid().cells(), [4; 8]);
+        assert_eq!(
+            Vec2D::from_atomic(grid),
+            Vec2D::new_with_default(4, 2, 4).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn for_each_row_parallel_visits_all_rows() {
+        let mut grid = Vec2D::<usize>::new(4, 7).unwrap();
+
+        for threads in [1, 3, 16] {
+            grid.for_each_row_parallel(threads, |y, row| {
+                for (x, cell) in row.iter_mut().enumerate() {
+                    *cell += y * 4 + x;
+                }
+            });
+        }
+
+        let expected: Vec<usize> = (0..28).map(|idx| idx * 3).collect();
+        assert_eq!(grid.cells(), expected);
+    }
+
+    #[test]
+    fn fill_and_count_value() {
+        let mut grid = Vec2D::from_vec(vec![1u8, 2, 3, 4, 5, 6], 3).unwrap();
+
+        grid.fill(7);
+        assert_eq!(grid.count_value(&7), 6);
+
+        let mut next = 0;
+        grid.fill_with(|| {
+            next += 1;
+            next % 2
+        });
+        assert_eq!(grid.cells(), [1, 0, 1, 0, 1, 0]);
+        assert_eq!(grid.count_value(&1), 3);
+
+        let other = Vec2D::from_vec(grid.cells().to_vec(), 2).unwrap();
+        assert_ne!(grid, other);
+        assert_eq!(grid, Vec2D::from_vec(vec![1, 0, 1, 0, 1, 0], 3).unwrap());
+    }
+
+    #[test]
+    fn overwrite_rows_in_place() {
+        let mut grid = Vec2D::from_vec((0..6).collect(), 3).unwrap();
+
+        grid.copy_row_from_slice(1, &[7, 8, 9]).unwrap();
+        assert_eq!(grid.cells(), [0, 1, 2, 7, 8, 9]);
+        assert_eq!(
+            grid.copy_row_from_slice(2, &[0, 0, 0]),
+            Err(Vec2DErr::RowOutOfBounds { y: 2, height: 2 })
+        );
+
+        let cells = grid.cells().iter().map(|cell| cell.to_string()).collect();
+        let mut grid = Vec2D::from_vec(cells, 3).unwrap();
+        grid.clone_row_from(0, &["a".into(), "b".into(), "c".into()])
+            .unwrap();
+        assert_eq!(grid.get_row(0).unwrap(), ["a", "b", "c"]);
+        assert_eq!(
+            grid.clone_row_from(0, &["a".into()]),
+            Err(Vec2DErr::WidthMismatch {
+                expected: 3,
+                actual: 1
+            })
+        );
+    }
+
+    fn empty_fixtures() -> [Vec2D<u16>; 3] {
+        let mut cleared = Vec2D::from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        cleared.clear();
+
+        [Vec2D::default(), Vec2D::empty(3).unwrap(), cleared]
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_empty_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Map {
+            #[serde(with = "crate::serde_rows")]
+            tiles: Vec2D<u16>,
+        }
+
+        for grid in empty_fixtures() {
+            let json = serde_json::to_string(&grid).unwrap();
+            let parsed: Vec2D<u16> = serde_json::from_str(&json).unwrap();
+            assert_eq!((parsed.width(), parsed.height()), (grid.width(), 0));
+
+            let json = serde_json::to_string(&Map {
+                tiles: grid.clone(),
+            })
+            .unwrap();
+            let parsed: Map = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.tiles.width(), grid.width());
+            assert_eq!(parsed.tiles.height(), 0);
+        }
+
+        let parsed: Map = serde_json::from_str(r#"{"tiles":[]}"#).unwrap();
+        assert_eq!(parsed.tiles, Vec2D::default());
+    }
+
+    #[test]
+    fn snapshot_empty_round_trip() {
+        for grid in empty_fixtures() {
+            let parsed = Vec2D::<u16>::from_snapshot(&grid.to_snapshot()).unwrap();
+            assert_eq!((parsed.width(), parsed.height()), (grid.width(), 0));
+        }
+    }
+
+    #[test]
+    fn rle_empty_round_trip() {
+        for grid in empty_fixtures() {
+            let runs = grid.rle_encode();
+            assert!(runs.is_empty());
+
+            let parsed = Vec2D::rle_decode(&runs, grid.width()).unwrap();
+            assert_eq!((parsed.width(), parsed.height()), (grid.width(), 0));
+        }
+    }
 }
@@ -0,0 +1,125 @@
+use core::ops::Deref;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// A wrapper around [`Vec2D`], which records the cells that were mutated
+/// since the last call to [`TrackedVec2D::clear_dirty`].
+///
+/// All read-only methods of the grid are available through `Deref`, while
+/// every write has to go through the wrapper, so none can be missed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedVec2D<T> {
+    grid: Vec2D<T>,
+    // One flag per cell, so marking a cell twice doesn't record it twice.
+    flags: Vec<bool>,
+    dirty: Vec<usize>,
+}
+
+impl<T> From<Vec2D<T>> for TrackedVec2D<T> {
+    fn from(value: Vec2D<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Deref for TrackedVec2D<T> {
+    type Target = Vec2D<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.grid
+    }
+}
+
+/// Indexes into the grid using `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::Index<(usize, usize)> for TrackedVec2D<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        &self.grid[pos]
+    }
+}
+
+/// Mutably indexes into the grid using `(x, y)` coordinates, marking the
+/// cell as dirty.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::IndexMut<(usize, usize)> for TrackedVec2D<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        if let Some(idx) = self.grid.index_of(x, y) {
+            self.mark(idx);
+        }
+
+        // Out of bounds coordinates panic here, with the grid's message.
+        &mut self.grid[(x, y)]
+    }
+}
+
+impl<T> TrackedVec2D<T> {
+    /// Wraps a grid, with no cells marked as dirty.
+    pub fn new(grid: Vec2D<T>) -> Self {
+        Self {
+            flags: vec![false; grid.cells.len()],
+            dirty: Vec::new(),
+            grid,
+        }
+    }
+
+    /// Unwraps the grid, discarding the recorded changes.
+    pub fn into_inner(self) -> Vec2D<T> {
+        self.grid
+    }
+
+    fn mark(&mut self, idx: usize) {
+        if !self.flags[idx] {
+            self.flags[idx] = true;
+            self.dirty.push(idx);
+        }
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`, if it exists,
+    /// marking it as dirty.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        let idx = self.grid.index_of(x, y)?;
+        self.mark(idx);
+        Some(&mut self.grid.cells[idx])
+    }
+
+    /// Sets the cell at `(x, y)` to `value`, marking it as dirty.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::OutOfBounds` if the coordinates are out of bounds.
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<(), Vec2DErr> {
+        let err = self.grid.out_of_bounds(x, y);
+        let cell = self.get_mut(x, y).ok_or(err)?;
+        *cell = value;
+        Ok(())
+    }
+
+    /// Returns `true` if the cell at `(x, y)` was mutated since the last call
+    /// to `clear_dirty`.
+    pub fn is_dirty(&self, x: usize, y: usize) -> bool {
+        self.grid.index_of(x, y).is_some_and(|idx| self.flags[idx])
+    }
+
+    /// Iterates over the `(x, y)` coordinates of all cells mutated since the
+    /// last call to `clear_dirty`, in the order they were first mutated.
+    pub fn dirty_iter(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.grid.width;
+        self.dirty
+            .iter()
+            .map(move |&idx| (idx % width, idx / width))
+    }
+
+    /// Unmarks all dirty cells.
+    pub fn clear_dirty(&mut self) {
+        for idx in self.dirty.drain(..) {
+            self.flags[idx] = false;
+        }
+    }
+}
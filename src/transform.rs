@@ -0,0 +1,266 @@
+use core::cmp::Ordering;
+
+use alloc::vec::Vec;
+
+use crate::{Vec2D, Vec2DErr};
+
+/// How [`Vec2D::resize_width`] fits the cells into the new width.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResizePolicy<T> {
+    /// Keeps the cells in the same row-major order, reinterpreting them with
+    /// the new width, which changes the height.
+    Reflow,
+    /// Keeps the height, cutting off the end of every row, or padding it with
+    /// the given value.
+    PadTruncate(T),
+}
+
+impl<T> Vec2D<T> {
+    /// Reverses the order of the cells in row `y`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y` is out of bounds.
+    pub fn reverse_row(&mut self, y: usize) -> Result<(), Vec2DErr> {
+        self.check_row(y)?;
+        self.get_row_mut(y).unwrap().reverse();
+        Ok(())
+    }
+
+    /// Reverses the order of the cells in column `x`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ColOutOfBounds` if `x` is out of bounds.
+    pub fn reverse_col(&mut self, x: usize) -> Result<(), Vec2DErr> {
+        self.check_col(x)?;
+
+        self.reverse_col_range(x, 0, self.height());
+        Ok(())
+    }
+
+    /// Cyclically shifts row `y` by `offset` cells. Positive offsets shift
+    /// the cells to the right.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::RowOutOfBounds` if `y` is out of bounds.
+    pub fn rotate_row(&mut self, y: usize, offset: isize) -> Result<(), Vec2DErr> {
+        self.check_row(y)?;
+        let width = self.width;
+        let row = self.get_row_mut(y).unwrap();
+
+        row.rotate_right(offset.rem_euclid(width as isize) as usize);
+        Ok(())
+    }
+
+    /// Cyclically shifts column `x` by `offset` cells. Positive offsets shift
+    /// the cells down.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ColOutOfBounds` if `x` is out of bounds.
+    pub fn rotate_col(&mut self, x: usize, offset: isize) -> Result<(), Vec2DErr> {
+        self.check_col(x)?;
+
+        let height = self.height();
+        if height == 0 {
+            return Ok(());
+        }
+
+        // Rotating right by `k` is reversing the whole column, then reversing
+        // the first `k` and the remaining cells separately.
+        let k = offset.rem_euclid(height as isize) as usize;
+        if k != 0 {
+            self.reverse_col_range(x, 0, height);
+            self.reverse_col_range(x, 0, k);
+            self.reverse_col_range(x, k, height);
+        }
+        Ok(())
+    }
+
+    /// Transposes the grid in place, swapping the cells across the main
+    /// diagonal, without allocating.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::HeightMismatch` if the grid is not square.
+    pub fn transpose_in_place(&mut self) -> Result<(), Vec2DErr> {
+        self.check_square()?;
+
+        let n = self.width;
+        for y in 0..n {
+            for x in y + 1..n {
+                self.cells.swap(y * n + x, x * n + y);
+            }
+        }
+        Ok(())
+    }
+
+    /// Rotates the grid 90 degrees clockwise in place, without allocating.
+    ///
+    /// Each ring of the grid is rotated by cycling four cells at a time, which
+    /// only works for square grids; non-square grids change their dimensions
+    /// when rotated, and have to be copied instead.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::HeightMismatch` if the grid is not square.
+    pub fn rotate_90_in_place(&mut self) -> Result<(), Vec2DErr> {
+        self.check_square()?;
+
+        let n = self.width;
+        let idx = |row: usize, col: usize| row * n + col;
+        for i in 0..n / 2 {
+            for j in i..n - 1 - i {
+                let top = idx(i, j);
+                let left = idx(n - 1 - j, i);
+                let bottom = idx(n - 1 - i, n - 1 - j);
+                let right = idx(j, n - 1 - i);
+
+                // left -> top -> right -> bottom -> left
+                self.cells.swap(top, left);
+                self.cells.swap(left, bottom);
+                self.cells.swap(bottom, right);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the grid is square.
+    fn check_square(&self) -> Result<(), Vec2DErr> {
+        if self.height() != self.width {
+            return Err(Vec2DErr::HeightMismatch {
+                expected: self.width,
+                actual: self.height(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Changes the width of the grid according to `policy`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `new_width == 0`.
+    ///
+    /// Returns `Vec2DErr::WidthMismatch` when reflowing, if the number of cells
+    /// is not a multiple of `new_width`.
+    ///
+    /// Returns `Vec2DErr::TooLarge` when padding, if the widened cells
+    /// wouldn't fit into memory.
+    pub fn resize_width(
+        &mut self,
+        new_width: usize,
+        policy: ResizePolicy<T>,
+    ) -> Result<(), Vec2DErr>
+    where
+        T: Clone,
+    {
+        if new_width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        }
+
+        match policy {
+            ResizePolicy::Reflow => {
+                if !self.cells.len().is_multiple_of(new_width) {
+                    return Err(Vec2DErr::WidthMismatch {
+                        expected: new_width,
+                        actual: self.cells.len(),
+                    });
+                }
+            }
+            ResizePolicy::PadTruncate(_) if new_width <= self.width => {
+                self.truncate_cols(new_width)?;
+            }
+            ResizePolicy::PadTruncate(fill) => {
+                let height = self.height();
+                let mut cells = Vec::with_capacity(Self::checked_len(new_width, height)?);
+                let mut old = core::mem::take(&mut self.cells).into_iter();
+                for _ in 0..height {
+                    cells.extend(old.by_ref().take(self.width));
+                    cells.resize(cells.len() + new_width - self.width, fill.clone());
+                }
+                self.cells = cells;
+            }
+        }
+
+        self.width = new_width;
+        self.debug_validate();
+        Ok(())
+    }
+
+    /// Sorts the rows of the grid with the comparator function `cmp`,
+    /// moving whole rows in place.
+    ///
+    /// The sort is stable, so equal rows keep their order.
+    pub fn sort_rows_by(&mut self, mut cmp: impl FnMut(&[T], &[T]) -> Ordering) {
+        let rows: Vec<&[T]> = self.iter_rows().collect();
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by(|&a, &b| cmp(rows[a], rows[b]));
+
+        self.permute_rows(order);
+    }
+
+    /// Sorts the rows of the grid by the key returned from `f`, moving whole
+    /// rows in place.
+    ///
+    /// The sort is stable, so rows with equal keys keep their order.
+    pub fn sort_rows_by_key<K: Ord>(&mut self, mut f: impl FnMut(&[T]) -> K) {
+        let keys: Vec<K> = self.iter_rows().map(&mut f).collect();
+        let mut order: Vec<usize> = (0..keys.len()).collect();
+        order.sort_by(|&a, &b| keys[a].cmp(&keys[b]));
+
+        self.permute_rows(order);
+    }
+
+    /// Reorders the rows, so that row `y` becomes the old row `order[y]`.
+    ///
+    /// Every cycle of the permutation is resolved in place by swapping rows
+    /// along it, so a cycle of `n` rows takes `n - 1` row swaps and no extra
+    /// buffer.
+    fn permute_rows(&mut self, mut order: Vec<usize>) {
+        for start in 0..order.len() {
+            let mut y = start;
+            while order[y] != start {
+                let next = order[y];
+                self.swap_rows(y, next);
+                // Mark the row as placed.
+                order[y] = y;
+                y = next;
+            }
+            order[y] = y;
+        }
+    }
+
+    /// Swaps the distinct rows `a` and `b`.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let (a, b) = (a.min(b), a.max(b));
+        let (head, tail) = self.cells.split_at_mut(b * self.width);
+        head[a * self.width..(a + 1) * self.width].swap_with_slice(&mut tail[..self.width]);
+    }
+
+    /// Reverses the cells of column `x` in rows `start..end`.
+    fn reverse_col_range(&mut self, x: usize, start: usize, end: usize) {
+        for i in 0..(end - start) / 2 {
+            self.cells
+                .swap((start + i) * self.width + x, (end - 1 - i) * self.width + x);
+        }
+    }
+
+    /// Cyclically shifts the whole grid by `dx` columns and `dy` rows, as if
+    /// it was wrapped around a torus.
+    ///
+    /// Positive offsets shift the cells right and down, so the cell at
+    /// `(x, y)` ends up at `((x + dx) mod width, (y + dy) mod height)`.
+    pub fn roll(&mut self, dx: isize, dy: isize) {
+        let height = self.height();
+        if height == 0 {
+            return;
+        }
+
+        let dy = dy.rem_euclid(height as isize) as usize;
+        self.cells.rotate_right(dy * self.width);
+
+        let dx = dx.rem_euclid(self.width as isize) as usize;
+        if dx != 0 {
+            for row in self.cells.chunks_exact_mut(self.width) {
+                row.rotate_right(dx);
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use alloc::vec::Vec;
+
+use crate::{Rect, Vec2D, Vec2DErr};
+
+/// A borrowed, rectangular window into a [`Vec2D`].
+///
+/// Created by [`Vec2D::view`]. Coordinates are relative to the top-left
+/// corner of the window.
+#[derive(Debug)]
+pub struct View<'a, T> {
+    grid: &'a Vec2D<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+/// Indexes into the view using `(x, y)` coordinates.
+///
+/// # Panics
+/// Panics if `x` or `y` is out of bounds.
+impl<T> core::ops::Index<(usize, usize)> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < self.width && y < self.height,
+            // Panic message
+            "View index out of bounds: (x: {}, y: {}) in a {}x{} view.",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+
+        &self.grid[(self.x + x, self.y + y)]
+    }
+}
+
+impl<T> Vec2D<T> {
+    /// Returns a borrowed view of the cells in `rect`.
+    ///
+    /// # Errors
+    /// Returns `Vec2DErr::ZeroWidth` if `rect.width == 0`.
+    ///
+    /// Returns `Vec2DErr::ZeroHeight` if `rect.height == 0`.
+    ///
+    /// Returns `Vec2DErr::OutOfBounds` with the rectangle's bottom-right
+    /// corner if the rectangle doesn't fit into the grid.
+    pub fn view(&self, rect: Rect) -> Result<View<'_, T>, Vec2DErr> {
+        self.check_rect(rect)?;
+
+        Ok(View {
+            grid: self,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        })
+    }
+
+    /// Checks that `rect` is non-empty and fits into the grid.
+    pub(crate) fn check_rect(&self, rect: Rect) -> Result<(), Vec2DErr> {
+        if rect.width == 0 {
+            return Err(Vec2DErr::ZeroWidth);
+        } else if rect.height == 0 {
+            return Err(Vec2DErr::ZeroHeight);
+        }
+        if rect
+            .x
+            .checked_add(rect.width)
+            .is_none_or(|end| end > self.width)
+            || rect
+                .y
+                .checked_add(rect.height)
+                .is_none_or(|end| end > self.height())
+        {
+            return Err(self.out_of_bounds(
+                rect.x.saturating_add(rect.width - 1),
+                rect.y.saturating_add(rect.height - 1),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T> View<'a, T> {
+    /// Returns the width of the view.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the view.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the coordinates of the view's top-left corner in the
+    /// underlying grid.
+    #[inline]
+    pub const fn origin(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Returns a shared reference to the cell at `(x, y)`, if it exists.
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a shared slice representing row `y` of the view, if it exists.
+    pub fn get_row(&self, y: usize) -> Option<&'a [T]> {
+        if y >= self.height {
+            return None;
+        }
+
+        let row = self.grid.get_row(self.y + y)?;
+        Some(&row[self.x..self.x + self.width])
+    }
+
+    /// Iterates over the view's rows as shared slices.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+        let view = *self;
+        (0..self.height).filter_map(move |y| view.get_row(y))
+    }
+
+    /// Iterates over all cells, yielding their `(x, y)` coordinates (relative
+    /// to the view) and values.
+    pub fn iter_xy(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + use<'a, T> {
+        self.iter_rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+
+    /// Copies the viewed cells into a new grid.
+    pub fn to_vec2d(&self) -> Vec2D<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.width * self.height);
+        for row in self.iter_rows() {
+            cells.extend_from_slice(row);
+        }
+
+        Vec2D {
+            cells,
+            width: self.width,
+        }
+    }
+}